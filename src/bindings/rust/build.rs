@@ -202,6 +202,14 @@ fn build_stubs(cc_builder: &mut cc::Build) {
     println!("cargo:rerun-if-changed=wrapper.h");
 }
 
+fn link_cuda() {
+    let cuda_home = env::var("CUDA_HOME").unwrap_or_else(|_| "/usr/local/cuda".to_string());
+    println!("cargo:rustc-link-search=native={}/lib64", cuda_home);
+    println!("cargo:rustc-link-lib=dylib=cudart");
+    println!("cargo:rustc-link-lib=dylib=cuda");
+    println!("cargo:rerun-if-env-changed=CUDA_HOME");
+}
+
 fn run_build(use_stub_api: bool) {
     let mut cc_builder = cc::Build::new();
     cc_builder
@@ -224,4 +232,8 @@ fn main() {
     let use_stub_api = cfg!(feature = "stub-api");
 
     run_build(use_stub_api);
+
+    if cfg!(feature = "cuda") {
+        link_cuda();
+    }
 }
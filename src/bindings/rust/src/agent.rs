@@ -15,28 +15,129 @@
 
 use super::*;
 use crate::descriptors::{QueryResponseList, RegDescList};
+use std::collections::VecDeque;
+use std::net::{SocketAddr, TcpListener};
+use std::path::Path;
+use std::sync::Mutex;
+
+mod builder;
+mod config;
+mod metadata;
+mod peer;
+
+pub use builder::AgentBuilder;
+pub use config::{AgentConfig, BackendConfig};
+pub use metadata::Metadata;
+pub use peer::RemotePeer;
 
 /// A NIXL agent that can create backends and manage memory
-#[derive(Debug, Clone)]
+///
+/// # Thread safety
+///
+/// `Agent` is `Send` and `Sync`, and clones share the same underlying agent.
+/// NIXL agents are created with reader-writer locking, so transfer calls
+/// such as `create_xfer_req`, `post_xfer_req` and `get_xfer_status` may be
+/// made from many threads at once. Calls that change the agent's state,
+/// such as `create_backend`, `register_memory` and `load_remote_md`, take
+/// NIXL's exclusive lock and wait for transfer calls in flight.
+///
+/// The bindings also keep bookkeeping, such as posted requests and queued
+/// notifications, behind a lock of their own. Posting and polling hold it
+/// for the length of the FFI call, so heavy concurrent posting is
+/// serialized there rather than inside NIXL.
+///
+/// Requests keep the agent alive, so dropping the last `Agent` while a
+/// transfer is in flight is safe: the NIXL agent is destroyed after its
+/// requests are dropped, and an in-progress request that the backend cannot
+/// cancel is released by the agent once it finishes. Dropping the agent waits
+/// a bounded time for such requests; use `shutdown` to wait for them
+/// explicitly and see any errors.
+#[derive(Clone)]
 pub struct Agent {
     inner: Arc<RwLock<AgentInner>>,
+    error_log: Option<Arc<Mutex<ErrorLog>>>,
+}
+
+impl fmt::Debug for Agent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Agent").field("name", &self.name()).finish()
+    }
 }
 
+/// Rolling log of errors returned by an agent
+type ErrorLog = VecDeque<(Instant, NixlError)>;
+
+/// Number of errors kept by an agent that tracks errors
+const ERROR_LOG_CAPACITY: usize = 64;
+
+/// First and longest sleep between status checks in `Agent::wait_xfer`
+const WAIT_BACKOFF_MIN: Duration = Duration::from_micros(10);
+const WAIT_BACKOFF_MAX: Duration = Duration::from_millis(10);
+
+/// Backend parameters read by the bindings, which are not passed to plugins
+const WRAPPER_PARAMS: [&str; 3] = [
+    MAX_TRANSFER_SIZE_PARAM,
+    QUEUE_CAPACITY_PARAM,
+    MAX_DESCRIPTORS_PARAM,
+];
+
+/// Longest time dropping an agent waits for requests that could not be released
+const DEFERRED_RELEASE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Notification prefixes of `Agent::ping` and its echo, followed by an 8-byte id
+const PING_PREFIX: &[u8] = b"nixl_ping";
+const PONG_PREFIX: &[u8] = b"nixl_pong";
+
+/// Longest time `Agent::ping` waits for the remote agent to echo a ping
+const PING_TIMEOUT: Duration = Duration::from_secs(10);
+
 impl Agent {
     /// Creates a new agent with the given name
     pub fn new(name: &str) -> Result<Self, NixlError> {
+        Self::create(name, None)
+    }
+
+    /// Creates an agent whose listener thread accepts metadata and connection requests
+    ///
+    /// NIXL listens on every interface. A port of 0 is replaced by a free
+    /// port found by briefly binding `addr`, which also checks that the
+    /// address is local.
+    pub(crate) fn new_listening(name: &str, addr: SocketAddr) -> Result<Self, NixlError> {
+        let addr = if addr.port() == 0 {
+            TcpListener::bind(addr)
+                .and_then(|listener| listener.local_addr())
+                .map_err(|e| NixlError::Io(e.kind()))?
+        } else {
+            addr
+        };
+        Self::create(name, Some(addr))
+    }
+
+    fn create(name: &str, listen_addr: Option<SocketAddr>) -> Result<Self, NixlError> {
         tracing::trace!(agent.name = %name, "Creating new NIXL agent");
         let c_name = CString::new(name)?;
         let mut agent = ptr::null_mut();
-        let status = unsafe { nixl_capi_create_agent(c_name.as_ptr(), &mut agent) };
+        let status = unsafe {
+            match listen_addr {
+                Some(addr) => nixl_capi_create_agent_with_listener(
+                    c_name.as_ptr(),
+                    addr.port().into(),
+                    &mut agent,
+                ),
+                None => nixl_capi_create_agent(c_name.as_ptr(), &mut agent),
+            }
+        };
 
         match status {
             NIXL_CAPI_SUCCESS => {
                 // SAFETY: If status is NIXL_CAPI_SUCCESS, agent is non-null
                 let handle = unsafe { NonNull::new_unchecked(agent) };
                 tracing::trace!(agent.name = %name, "Successfully created NIXL agent");
+                let mut inner = AgentInner::new(handle, name.to_string());
+                inner.listen_addr = listen_addr;
                 Ok(Self {
-                    inner: Arc::new(RwLock::new(AgentInner::new(handle, name.to_string()))),
+                    inner: Arc::new(RwLock::new(inner)),
+                    error_log: None,
                 })
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
@@ -50,11 +151,101 @@ impl Agent {
         }
     }
 
+    /// Creates a new agent with the same backends as the given configuration
+    ///
+    /// Backends are created with the recorded parameters, skipping plugin
+    /// parameter discovery.
+    pub fn new_from_config(name: &str, config: &AgentConfig) -> Result<Self, NixlError> {
+        let agent = Self::new(name)?;
+        for backend in &config.backends {
+            let mut params = utils::Params::empty()?;
+            for (key, value) in &backend.params {
+                params.set(key, value)?;
+            }
+            agent.create_backend(&backend.plugin, &params)?;
+        }
+        Ok(agent)
+    }
+
+    /// Captures the backends of this agent and their parameters
+    pub fn config(&self) -> Result<AgentConfig, NixlError> {
+        let inner = self.inner.read().unwrap();
+        let mut backends = Vec::with_capacity(inner.backends.len());
+        for (plugin, backend) in inner.backends.iter() {
+            let (_mems, params) = inner.backend_params(*backend)?;
+            let mut params = params
+                .iter()?
+                .map(|pair| pair.map(|pair| (pair.key.to_string(), pair.value.to_string())))
+                .collect::<Result<Vec<_>, _>>()?;
+            // Limits declared to the bindings are not part of the plugin's parameters
+            if let Some(caps) = inner.backend_caps.get(backend) {
+                let limits = [
+                    (MAX_TRANSFER_SIZE_PARAM, caps.max_transfer_size),
+                    (QUEUE_CAPACITY_PARAM, caps.queue_capacity),
+                    (MAX_DESCRIPTORS_PARAM, caps.max_descriptors),
+                ];
+                for (key, limit) in limits {
+                    if let Some(limit) = limit {
+                        params.push((key.to_string(), limit.to_string()));
+                    }
+                }
+            }
+            backends.push(BackendConfig {
+                plugin: plugin.clone(),
+                params,
+            });
+        }
+        backends.sort_by(|a, b| a.plugin.cmp(&b.plugin));
+        Ok(AgentConfig { backends })
+    }
+
+    /// Enables the rolling log of FFI errors returned by this agent
+    pub(crate) fn enable_error_log(&mut self) {
+        self.error_log = Some(Arc::new(Mutex::new(VecDeque::with_capacity(
+            ERROR_LOG_CAPACITY,
+        ))));
+    }
+
+    /// Appends a failed result to the error log, if error tracking is enabled
+    fn record<T>(&self, result: Result<T, NixlError>) -> Result<T, NixlError> {
+        if let (Err(e), Some(log)) = (&result, &self.error_log) {
+            let mut log = log.lock().unwrap();
+            if log.len() == ERROR_LOG_CAPACITY {
+                log.pop_front();
+            }
+            log.push_back((Instant::now(), e.clone()));
+        }
+        result
+    }
+
+    /// Returns the most recent errors, oldest first
+    ///
+    /// Errors are only collected when the agent was built with
+    /// `AgentBuilder::track_errors(true)`; otherwise this is always empty.
+    pub fn recent_errors(&self) -> Vec<(Instant, NixlError)> {
+        self.error_log
+            .as_ref()
+            .map(|log| log.lock().unwrap().iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Clears the error log
+    pub fn clear_error_log(&self) {
+        if let Some(log) = &self.error_log {
+            log.lock().unwrap().clear();
+        }
+    }
+
     /// Gets the name of the agent
     pub fn name(&self) -> String {
         self.inner.read().unwrap().name.clone()
     }
 
+    /// Returns the address the agent listens on, if it was built with `AgentBuilder::listen_on`
+    pub fn listener_addr(&self) -> Option<SocketAddr> {
+        self.inner.read().unwrap().listen_addr
+    }
+
     /// Gets the list of available plugins
     pub fn get_available_plugins(&self) -> Result<utils::StringList, NixlError> {
         tracing::trace!("Getting available NIXL plugins");
@@ -86,6 +277,15 @@ impl Agent {
         }
     }
 
+    /// Gets the list of available plugins as typed plugin kinds
+    pub fn get_available_plugin_kinds(&self) -> Result<Vec<PluginKind>, NixlError> {
+        let plugins = self.get_available_plugins()?;
+        plugins
+            .iter()
+            .map(|name| name.map(PluginKind::from))
+            .collect()
+    }
+
     /// Gets the parameters for a plugin
     ///
     /// # Arguments
@@ -138,6 +338,10 @@ impl Agent {
         params: &utils::Params,
     ) -> Result<Backend, NixlError> {
         tracing::trace!(plugin.name = %plugin, "Creating new NIXL backend");
+        let max_transfer_size = self.record(declared_limit(params, MAX_TRANSFER_SIZE_PARAM))?;
+        let queue_capacity = self.record(declared_limit(params, QUEUE_CAPACITY_PARAM))?;
+        let max_descriptors = self.record(declared_limit(params, MAX_DESCRIPTORS_PARAM))?;
+        let plugin_params = self.record(params.without(&WRAPPER_PARAMS))?;
         let c_plugin = CString::new(plugin).map_err(|_| NixlError::InvalidParam)?;
         let name = c_plugin.to_string_lossy().to_string();
         let mut backend = ptr::null_mut();
@@ -145,22 +349,29 @@ impl Agent {
             nixl_capi_create_backend(
                 self.inner.write().unwrap().handle.as_ptr(),
                 c_plugin.as_ptr(),
-                params.handle(),
+                plugin_params.handle(),
                 &mut backend,
             )
         };
 
-        match status {
+        let result = match status {
             NIXL_CAPI_SUCCESS => {
                 let backend_handle = NonNull::new(backend).ok_or(NixlError::BackendError)?;
-                self.inner
-                    .write()
-                    .unwrap()
-                    .backends
-                    .insert(name.clone(), backend_handle);
+                let mut inner = self.inner.write().unwrap();
+                let (mems, _params) = inner.backend_params(backend_handle)?;
+                let caps = BackendCaps {
+                    mem_types: mems.iter().collect::<Result<_, _>>()?,
+                    max_transfer_size,
+                    queue_capacity,
+                    max_descriptors,
+                };
+                inner.backends.insert(name.clone(), backend_handle);
+                inner.backend_caps.insert(backend_handle, caps);
+                drop(inner);
                 tracing::trace!(plugin.name = %plugin, "Successfully created NIXL backend");
                 Ok(Backend {
                     inner: backend_handle,
+                    agent: self.inner.clone(),
                 })
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
@@ -171,7 +382,28 @@ impl Agent {
                 tracing::error!(plugin.name = %plugin, error = "backend_error", "Failed to create NIXL backend");
                 Err(NixlError::BackendError)
             }
+        };
+        self.record(result)
+    }
+
+    /// Creates a backend from the first plugin in `preferred` that succeeds
+    ///
+    /// Each plugin is tried in order with its default parameters.
+    ///
+    /// # Errors
+    /// Returns `NixlError::NoBackendAvailable` listing each failure if no plugin could be used
+    pub fn create_backend_fallback(&self, preferred: &[&str]) -> Result<Backend, NixlError> {
+        let mut failures = Vec::with_capacity(preferred.len());
+        for &plugin in preferred {
+            let result = self
+                .get_plugin_params(plugin)
+                .and_then(|(_mems, params)| self.create_backend(plugin, &params));
+            match result {
+                Ok(backend) => return Ok(backend),
+                Err(e) => failures.push(format!("{plugin}: {e}")),
+            }
         }
+        Err(NixlError::NoBackendAvailable(failures.join("; ")))
     }
 
     /// Gets a backend by name
@@ -180,7 +412,57 @@ impl Agent {
             .read()
             .unwrap()
             .get_backend(name)
-            .map(|backend| Backend { inner: backend })
+            .map(|backend| Backend {
+                inner: backend,
+                agent: self.inner.clone(),
+            })
+    }
+
+    /// Sets the backend used by `register_memory` and `create_xfer_req`
+    /// when they are called without optional arguments
+    pub fn set_default_backend(&self, backend: &Backend) {
+        self.inner.write().unwrap().default_backend = Some(backend.inner);
+    }
+
+    /// Gets the default backend, if one was set
+    pub fn default_backend(&self) -> Option<Backend> {
+        self.inner
+            .read()
+            .unwrap()
+            .default_backend
+            .map(|backend| Backend {
+                inner: backend,
+                agent: self.inner.clone(),
+            })
+    }
+
+    /// Builds optional arguments selecting every backend created by this agent
+    pub fn default_opt_args(&self) -> Result<OptArgs, NixlError> {
+        let inner = self.inner.read().unwrap();
+        let mut args = OptArgs::new()?;
+        for backend in inner.backends.values() {
+            args.add_backend(&Backend {
+                inner: *backend,
+                agent: self.inner.clone(),
+            })?;
+        }
+        Ok(args)
+    }
+
+    /// Builds optional arguments selecting the default backend, if the caller
+    /// passed none and a default backend is set
+    fn fallback_opt_args(&self, opt_args: Option<&OptArgs>) -> Result<Option<OptArgs>, NixlError> {
+        if opt_args.is_some() {
+            return Ok(None);
+        }
+        match self.default_backend() {
+            Some(backend) => {
+                let mut args = OptArgs::new()?;
+                args.add_backend(&backend)?;
+                Ok(Some(args))
+            }
+            None => Ok(None),
+        }
     }
 
     /// Gets the parameters and memory types for a backend after initialization
@@ -225,25 +507,188 @@ impl Agent {
         descriptor: &impl NixlDescriptor,
         opt_args: Option<&OptArgs>,
     ) -> Result<RegistrationHandle, NixlError> {
+        let (handle, _) = self.register_region(descriptor, 0, opt_args)?;
+        Ok(handle)
+    }
+
+    /// Registers a memory descriptor through a guard that borrows both the agent and the memory
+    ///
+    /// The region is deregistered when the returned guard is dropped.
+    pub fn register_scoped<'a, D: NixlDescriptor>(
+        &'a self,
+        descriptor: &'a D,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<ScopedRegistration<'a>, NixlError> {
+        let handle = self.register_memory(descriptor, opt_args)?;
+        Ok(ScopedRegistration {
+            handle,
+            _borrows: PhantomData,
+        })
+    }
+
+    /// Registers a memory descriptor with the agent, attaching a user context
+    ///
+    /// The context is returned with the region by `Agent::region_info` and
+    /// `RegistrationHandle::info`.
+    ///
+    /// # Arguments
+    /// * `descriptor` - The memory descriptor to register
+    /// * `context` - Application data to keep with the region, such as an ID
+    /// * `opt_args` - Optional arguments for the registration
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` or `NixlError::BackendError` if NIXL
+    /// rejects the registration, for example when no backend can register it.
+    pub fn register_memory_with_context(
+        &self,
+        descriptor: &impl NixlDescriptor,
+        context: u64,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<RegistrationHandle, NixlError> {
+        let (mut handle, registered) = self.register_region(descriptor, context, opt_args)?;
+        if registered.is_err() {
+            // Nothing was registered, so the handle must not deregister
+            handle.detach();
+        }
+        self.record(registered.map(|()| handle))
+    }
+
+    /// Registers a region and returns its handle with the outcome of the registration
+    ///
+    /// Only regions NIXL accepted are reported by `region_info`.
+    fn register_region(
+        &self,
+        descriptor: &impl NixlDescriptor,
+        context: u64,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<(RegistrationHandle, Result<(), NixlError>), NixlError> {
+        let default_args = self.fallback_opt_args(opt_args)?;
+        let opt_args = opt_args.or(default_args.as_ref());
+
         let mut reg_dlist = RegDescList::new(descriptor.mem_type(), false)?;
-        unsafe {
+        let status = unsafe {
             reg_dlist.add_storage_desc(descriptor)?;
 
             nixl_capi_register_mem(
                 self.inner.write().unwrap().handle.as_ptr(),
                 reg_dlist.handle(),
                 opt_args.map_or(std::ptr::null_mut(), |args| args.inner.as_ptr()),
-            );
-        }
-        Ok(RegistrationHandle {
+            )
+        };
+
+        let handle = RegistrationHandle {
             agent: Some(self.inner.clone()),
             ptr: unsafe { descriptor.as_ptr() } as usize,
             size: descriptor.size(),
             dev_id: descriptor.device_id(),
             mem_type: descriptor.mem_type(),
+            context,
+        };
+        let registered = match status {
+            NIXL_CAPI_SUCCESS => {
+                self.inner.write().unwrap().regions.push(handle.info());
+                Ok(())
+            }
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        };
+        Ok((handle, registered))
+    }
+
+    /// Returns the registered region containing `addr`, if any
+    pub fn region_info(&self, addr: usize) -> Option<RegionInfo> {
+        self.inner
+            .read()
+            .unwrap()
+            .regions
+            .iter()
+            .find(|region| region.contains(addr))
+            .copied()
+    }
+
+    /// Returns totals over the memory currently registered through this agent
+    ///
+    /// Useful for capacity planning against backend limits such as the
+    /// number of memory regions an RDMA device supports.
+    pub fn registration_overhead(&self) -> Result<RegistrationStats, NixlError> {
+        let inner = self.inner.read().unwrap();
+        let count = inner.regions.len();
+        Ok(RegistrationStats {
+            total_bytes: inner.regions.iter().map(RegionInfo::size).sum(),
+            count,
+            backend_registrations: count * inner.backends.len(),
         })
     }
 
+    /// Returns true if the handle registered memory through this agent
+    pub(crate) fn owns_registration(&self, handle: &RegistrationHandle) -> bool {
+        handle
+            .agent
+            .as_ref()
+            .is_some_and(|agent| Arc::ptr_eq(agent, &self.inner))
+    }
+
+    /// Registers a memory region under each of the given memory types
+    ///
+    /// This allows a buffer that is addressable as several kinds of memory,
+    /// such as CUDA managed memory, to be targeted by transfers of any of them.
+    /// One registration handle is returned per memory type, in the same order.
+    /// Without `opt_args`, the default backend is used if one is set.
+    ///
+    /// # Arguments
+    /// * `descriptor` - The memory descriptor to register
+    /// * `mem_types` - Memory types to register the region under
+    /// * `opt_args` - Optional arguments for the registration
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if `mem_types` is empty. If any
+    /// registration fails, the ones that already succeeded are deregistered.
+    pub fn register_memory_as(
+        &self,
+        descriptor: &impl NixlDescriptor,
+        mem_types: &[MemType],
+        opt_args: Option<&OptArgs>,
+    ) -> Result<Vec<RegistrationHandle>, NixlError> {
+        if mem_types.is_empty() {
+            return Err(NixlError::InvalidParam);
+        }
+        let default_args = self.fallback_opt_args(opt_args)?;
+        let opt_args = opt_args.or(default_args.as_ref());
+
+        let ptr = unsafe { descriptor.as_ptr() } as usize;
+        let mut handles = Vec::with_capacity(mem_types.len());
+        for &mem_type in mem_types {
+            let mut reg_dlist = RegDescList::new(mem_type, false)?;
+            reg_dlist.add_desc(ptr, descriptor.size(), descriptor.device_id())?;
+
+            let status = unsafe {
+                nixl_capi_register_mem(
+                    self.inner.write().unwrap().handle.as_ptr(),
+                    reg_dlist.handle(),
+                    opt_args.map_or(std::ptr::null_mut(), |args| args.inner.as_ptr()),
+                )
+            };
+
+            match status {
+                NIXL_CAPI_SUCCESS => {
+                    let handle = RegistrationHandle {
+                        agent: Some(self.inner.clone()),
+                        ptr,
+                        size: descriptor.size(),
+                        dev_id: descriptor.device_id(),
+                        mem_type,
+                        context: 0,
+                    };
+                    self.inner.write().unwrap().regions.push(handle.info());
+                    handles.push(handle);
+                }
+                NIXL_CAPI_ERROR_INVALID_PARAM => return Err(NixlError::InvalidParam),
+                _ => return Err(NixlError::BackendError),
+            }
+        }
+        Ok(handles)
+    }
+
     /// Query information about memory/storage
     ///
     /// # Arguments
@@ -272,15 +717,32 @@ impl Agent {
             }
         };
 
-        match status {
+        let result = match status {
             NIXL_CAPI_SUCCESS => Ok(resp),
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
             _ => Err(NixlError::BackendError),
-        }
+        };
+        self.record(result)
     }
 
     /// Gets the local metadata for this agent as a byte array
     pub fn get_local_md(&self) -> Result<Vec<u8>, NixlError> {
+        let mut metadata = Vec::new();
+        self.get_local_md_into(&mut metadata)?;
+        Ok(metadata)
+    }
+
+    /// Gets the local metadata for this agent as a `Metadata` blob
+    pub fn local_metadata(&self) -> Result<Metadata, NixlError> {
+        let metadata = self.get_local_md()?;
+        self.record(Metadata::try_from(metadata))
+    }
+
+    /// Gets the local metadata for this agent into a caller-provided buffer
+    ///
+    /// The buffer is cleared and refilled, so its allocation is reused when
+    /// polling metadata repeatedly.
+    pub fn get_local_md_into(&self, buf: &mut Vec<u8>) -> Result<(), NixlError> {
         tracing::trace!("Getting local metadata");
         let mut data = std::ptr::null_mut();
         let mut len = 0;
@@ -303,16 +765,16 @@ impl Agent {
             return Err(NixlError::InvalidDataPointer);
         }
 
-        match status {
+        let result = match status {
             NIXL_CAPI_SUCCESS => {
-                let bytes = unsafe {
+                unsafe {
                     let slice = std::slice::from_raw_parts(data, len);
-                    let vec = slice.to_vec();
+                    buf.clear();
+                    buf.extend_from_slice(slice);
                     libc::free(data as *mut libc::c_void);
-                    vec
-                };
+                }
                 tracing::trace!(metadata.size = len, "Successfully retrieved local metadata");
-                Ok(bytes)
+                Ok(())
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
                 tracing::error!(error = "invalid_param", "Failed to get local metadata");
@@ -322,63 +784,96 @@ impl Agent {
                 tracing::error!(error = "backend_error", "Failed to get local metadata");
                 Err(NixlError::BackendError)
             }
-        }
+        };
+        self.record(result)
     }
 
     /// Loads remote metadata from a byte slice
     pub fn load_remote_md(&self, metadata: &[u8]) -> Result<String, NixlError> {
-        tracing::trace!(metadata.size = metadata.len(), "Loading remote metadata");
-        let mut agent_name = std::ptr::null_mut();
+        let result = self.inner.write().unwrap().load_remote_md(metadata);
+        self.record(result)
+    }
 
-        let status = unsafe {
-            nixl_capi_load_remote_md(
-                self.inner.write().unwrap().handle.as_ptr(),
-                metadata.as_ptr() as *const std::ffi::c_void,
-                metadata.len(),
-                &mut agent_name,
-            )
-        };
+    /// Loads remote metadata and describes the remote agent it belongs to
+    ///
+    /// Like `load_remote_md`, but also reports the memory types, backends and
+    /// number of regions found in the metadata.
+    pub fn load_remote_peer(&self, metadata: &[u8]) -> Result<RemotePeer, NixlError> {
+        let peer = self.record(RemotePeer::parse(metadata))?;
+        self.load_remote_md(metadata)?;
+        Ok(peer)
+    }
 
-        match status {
-            NIXL_CAPI_SUCCESS => {
-                let name = unsafe {
-                    let c_str = std::ffi::CStr::from_ptr(agent_name);
-                    let s = c_str.to_str().unwrap().to_string();
-                    libc::free(agent_name as *mut libc::c_void);
-                    s
-                };
-                self.inner.write().unwrap().remotes.insert(name.clone());
-                tracing::trace!(remote.agent = %name, "Successfully loaded remote metadata");
-                Ok(name)
-            }
-            NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(error = "invalid_param", "Failed to load remote metadata");
-                Err(NixlError::InvalidParam)
-            }
-            _ => {
-                tracing::error!(error = "backend_error", "Failed to load remote metadata");
-                Err(NixlError::BackendError)
-            }
-        }
+    /// Replaces the cached metadata of a remote agent, e.g. after it restarted
+    ///
+    /// The stale metadata is invalidated, which disconnects the remote from
+    /// every backend, and the new metadata is loaded before any other caller
+    /// can use the agent. Subsequent transfers use the new regions.
+    ///
+    /// # Arguments
+    /// * `remote_name` - The name of a previously loaded remote agent
+    /// * `new_md` - Fresh metadata published by that agent
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if `remote_name` was not loaded or
+    /// `new_md` belongs to a different agent
+    pub fn refresh_remote_md(&self, remote_name: &str, new_md: &[u8]) -> Result<(), NixlError> {
+        let result = self
+            .inner
+            .write()
+            .unwrap()
+            .refresh_remote_md(remote_name, new_md);
+        self.record(result)
+    }
+
+    /// Loads remote metadata previously written with `dump_local_md_to_file`
+    ///
+    /// Returns the name of the remote agent.
+    pub fn load_remote_md_from_file(&self, path: &Path) -> Result<String, NixlError> {
+        let metadata = self.record(std::fs::read(path).map_err(NixlError::from))?;
+        self.load_remote_md(&metadata)
+    }
+
+    /// Writes this agent's metadata to a file, for loading by another agent
+    pub fn dump_local_md_to_file(&self, path: &Path) -> Result<(), NixlError> {
+        let metadata = self.get_local_md()?;
+        self.record(std::fs::write(path, metadata).map_err(NixlError::from))
     }
 
     pub fn make_connection(&self, remote_agent: &str) -> Result<(), NixlError> {
-        let remote_agent = CString::new(remote_agent)?;
+        self.prep_xfer(remote_agent, None)
+    }
+
+    /// Establishes the connection to a remote agent ahead of the first transfer
+    ///
+    /// Call this after `load_remote_md` so the connection setup cost is paid
+    /// here rather than by the first posted request. `opt_args` can limit the
+    /// backends that connect. Connecting twice is harmless.
+    pub fn prep_xfer(
+        &self,
+        remote_name: &str,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<(), NixlError> {
+        if self.inner.read().unwrap().invalidated.contains(remote_name) {
+            return self.record(Err(NixlError::RemoteInvalidated(remote_name.to_string())));
+        }
+        let remote_agent = CString::new(remote_name)?;
         let inner_guard = self.inner.write().unwrap();
 
         let status = unsafe {
             nixl_capi_agent_make_connection(
                 inner_guard.handle.as_ptr(),
                 remote_agent.as_ptr(),
-                std::ptr::null_mut(),
+                opt_args.map_or(std::ptr::null_mut(), |args| args.inner.as_ptr()),
             )
         };
 
-        match status {
+        let result = match status {
             NIXL_CAPI_SUCCESS => Ok(()),
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
             _ => Err(NixlError::BackendError),
-        }
+        };
+        self.record(result)
     }
 
     /// Check if remote metadata for a specific agent is available
@@ -429,7 +924,50 @@ impl Agent {
         }
     }
 
+    /// Checks that every remote descriptor falls within a region registered by the remote agent
+    ///
+    /// # Arguments
+    /// * `remote` - Remote descriptor list to validate
+    /// * `remote_agent` - Name of the remote agent whose metadata was loaded
+    ///
+    /// # Errors
+    /// Returns `NixlError::RemoteRegionNotFound` with the index of the first
+    /// descriptor that is not covered, or `NixlError::BackendError` if no
+    /// metadata is loaded for the remote agent
+    pub fn probe_remote(&self, remote: &XferDescList, remote_agent: &str) -> Result<(), NixlError> {
+        let c_remote_name = CString::new(remote_agent)?;
+        let mut all_found = false;
+        let mut missing_index = 0;
+
+        let status = unsafe {
+            bindings::nixl_capi_probe_remote_md(
+                self.inner.read().unwrap().handle.as_ptr(),
+                c_remote_name.as_ptr(),
+                remote.handle(),
+                &mut all_found,
+                &mut missing_index,
+            )
+        };
+
+        let result = match status {
+            NIXL_CAPI_SUCCESS if all_found => Ok(()),
+            NIXL_CAPI_SUCCESS => Err(NixlError::RemoteRegionNotFound {
+                index: missing_index,
+            }),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        };
+        self.record(result)
+    }
+
     /// Invalidates a remote metadata for this agent
+    ///
+    /// The connection info of the remote agent is released, and
+    /// `create_xfer_req` targeting it returns `NixlError::RemoteInvalidated`
+    /// until its metadata is loaded again.
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if no metadata is loaded for `remote_agent`
     pub fn invalidate_remote_md(&self, remote_agent: &str) -> Result<(), NixlError> {
         self.inner
             .write()
@@ -437,11 +975,69 @@ impl Agent {
             .invalidate_remote_md(remote_agent)
     }
 
+    /// Returns the names of all remote agents whose metadata is loaded, sorted
+    ///
+    /// Agents requested with `fetch_remote_md` are included once the fetch was
+    /// issued, even if their metadata has not arrived yet.
+    pub fn remote_peers(&self) -> Result<Vec<String>, NixlError> {
+        let mut peers: Vec<_> = self.inner.read().unwrap().remotes.iter().cloned().collect();
+        peers.sort_unstable();
+        Ok(peers)
+    }
+
     /// Invalidates all remote metadata for this agent
     pub fn invalidate_all_remotes(&self) -> Result<(), NixlError> {
         self.inner.write().unwrap().invalidate_all_remotes()
     }
 
+    /// Shuts the agent down, waiting for posted transfers to finish
+    ///
+    /// Every posted request that is still in progress is polled until it
+    /// completes, then all remote metadata is invalidated. Unlike dropping the
+    /// agent, errors are returned instead of being ignored.
+    ///
+    /// The NIXL agent itself is destroyed once the last clone of this agent and
+    /// the last request created through it are dropped. A request dropped while
+    /// still in progress is released once it finishes, if the backend cannot
+    /// cancel it; `shutdown` waits for those as well.
+    ///
+    /// This never returns while a transfer is stalled; use `shutdown_timeout`
+    /// to bound the wait.
+    pub fn shutdown(self) -> Result<(), NixlError> {
+        self.shutdown_timeout(Duration::MAX)
+    }
+
+    /// Shuts the agent down like `shutdown`, waiting at most `timeout` for
+    /// posted transfers to finish
+    ///
+    /// # Errors
+    /// Returns `NixlError::Timeout` if transfers are still in progress after
+    /// `timeout`. Remote metadata is not invalidated in that case.
+    pub fn shutdown_timeout(self, timeout: Duration) -> Result<(), NixlError> {
+        // A timeout too long to represent waits without a deadline
+        let deadline = Instant::now().checked_add(timeout);
+        let mut backoff = WAIT_BACKOFF_MIN;
+        let result = loop {
+            // The lock is released between polls so other threads can use the agent
+            match self.inner.write().unwrap().poll_posted() {
+                Ok(true) => break self.inner.write().unwrap().invalidate_all_remotes(),
+                Ok(false) => {}
+                Err(e) => break Err(e),
+            }
+            let mut sleep = backoff;
+            if let Some(deadline) = deadline {
+                let now = Instant::now();
+                if now >= deadline {
+                    break Err(NixlError::Timeout);
+                }
+                sleep = sleep.min(deadline - now);
+            }
+            std::thread::sleep(sleep);
+            backoff = (backoff * 2).min(WAIT_BACKOFF_MAX);
+        };
+        self.record(result)
+    }
+
     /// Send this agent's metadata to etcdAdd commentMore actions
     ///
     /// This enables other agents to discover this agent's metadata via etcd.
@@ -458,7 +1054,7 @@ impl Agent {
             )
         };
 
-        match status {
+        let result = match status {
             NIXL_CAPI_SUCCESS => {
                 tracing::trace!("Successfully sent local metadata to etcd");
                 Ok(())
@@ -477,7 +1073,8 @@ impl Agent {
                 );
                 Err(NixlError::BackendError)
             }
-        }
+        };
+        self.record(result)
     }
 
     /// Fetch a remote agent's metadata from etcd
@@ -506,13 +1103,12 @@ impl Agent {
             )
         };
 
-        match status {
+        let result = match status {
             NIXL_CAPI_SUCCESS => {
-                self.inner
-                    .write()
-                    .unwrap()
-                    .remotes
-                    .insert(remote_name.to_string());
+                let mut inner = self.inner.write().unwrap();
+                inner.invalidated.remove(remote_name);
+                inner.remotes.insert(remote_name.to_string());
+                drop(inner);
                 tracing::trace!(remote_agent = %remote_name, "Successfully fetched remote metadata from etcd");
                 Ok(())
             }
@@ -524,7 +1120,8 @@ impl Agent {
                 tracing::error!(error = "backend_error", remote_agent = %remote_name, "Failed to fetch remote metadata from etcd");
                 Err(NixlError::BackendError)
             }
-        }
+        };
+        self.record(result)
     }
 
     /// Invalidate this agent's metadata in etcd
@@ -543,7 +1140,7 @@ impl Agent {
             )
         };
 
-        match status {
+        let result = match status {
             NIXL_CAPI_SUCCESS => {
                 tracing::trace!("Successfully invalidated local metadata in etcd");
                 Ok(())
@@ -562,7 +1159,8 @@ impl Agent {
                 );
                 Err(NixlError::BackendError)
             }
-        }
+        };
+        self.record(result)
     }
 
     /// Send a notification to a remote agent
@@ -607,7 +1205,7 @@ impl Agent {
             )
         };
 
-        match status {
+        let result = match status {
             NIXL_CAPI_SUCCESS => {
                 tracing::trace!(remote_agent = %remote_agent, "Successfully sent notification");
                 Ok(())
@@ -620,11 +1218,18 @@ impl Agent {
                 tracing::error!(error = "backend_error", remote_agent = %remote_agent, "Failed to send notification");
                 Err(NixlError::BackendError)
             }
-        }
+        };
+        self.record(result)
     }
 
     /// Creates a transfer request between local and remote descriptors
     ///
+    /// A `XferOp::Write` copies the local descriptors into the remote ones; a
+    /// `XferOp::Read` copies the remote descriptors into the local ones. Either
+    /// way, a notification requested in `opt_args` is delivered to the remote
+    /// agent once the transfer completes, so the owner of the source learns
+    /// that a read has finished with its buffers.
+    ///
     /// # Arguments
     /// * `operation` - The transfer operation (read or write)
     /// * `local_descs` - The local descriptor list
@@ -636,7 +1241,9 @@ impl Agent {
     /// A handle to the transfer request
     ///
     /// # Errors
-    /// Returns a NixlError if the operation fails
+    /// Returns a NixlError if the operation fails, and
+    /// `NixlError::OverlappingDescriptors` if the destination descriptors overlap
+    /// without `OptArgs::set_allow_overlaps`
     pub fn create_xfer_req(
         &self,
         operation: XferOp,
@@ -645,6 +1252,64 @@ impl Agent {
         remote_agent: &str,
         opt_args: Option<&OptArgs>,
     ) -> Result<XferRequest, NixlError> {
+        if self
+            .inner
+            .read()
+            .unwrap()
+            .invalidated
+            .contains(remote_agent)
+        {
+            return self.record(Err(NixlError::RemoteInvalidated(remote_agent.to_string())));
+        }
+        let checked =
+            self.inner
+                .read()
+                .unwrap()
+                .check_xfer(operation, local_descs, remote_descs, opt_args);
+        self.record(checked)?;
+
+        if !opt_args.is_some_and(|args| args.allow_overlaps()) {
+            let destination = match operation {
+                XferOp::Read => local_descs,
+                XferOp::Write => remote_descs,
+            };
+            match destination.has_overlaps_fast() {
+                Ok(false) => {}
+                Ok(true) => return self.record(Err(NixlError::OverlappingDescriptors)),
+                Err(e) => return self.record(Err(e)),
+            }
+        }
+
+        let crc = match opt_args {
+            Some(args) if args.append_crc() => Some(CrcPlan::new(operation, local_descs)?),
+            _ => None,
+        };
+        let layout = XferLayout::new(operation, local_descs, remote_descs, remote_agent)?;
+        let (transform, staged) = match opt_args.and_then(|args| args.transform()) {
+            Some(transform) => {
+                let (plan, staged) =
+                    TransformPlan::new(self, operation, transform.clone(), local_descs)?;
+                (Some(plan), Some(staged))
+            }
+            None => (None, None),
+        };
+        let mut prefix = Vec::new();
+        if opt_args.is_some_and(|args| args.notify_with_length()) {
+            prefix.extend_from_slice(&(layout.total_len() as u64).to_le_bytes());
+        }
+        let sequenced = opt_args.is_some_and(|args| args.notify_with_sequence());
+        let prefixed_args = match opt_args {
+            Some(args) if !prefix.is_empty() || sequenced => {
+                Some(args.with_notification_prefix(&prefix)?)
+            }
+            _ => None,
+        };
+        let default_args = self.fallback_opt_args(opt_args)?;
+        let opt_args = prefixed_args
+            .as_ref()
+            .or(opt_args)
+            .or(default_args.as_ref());
+
         let remote_agent = CString::new(remote_agent)?;
         let mut req = std::ptr::null_mut();
 
@@ -653,7 +1318,7 @@ impl Agent {
             bindings::nixl_capi_create_xfer_req(
                 self.inner.read().unwrap().handle.as_ptr(),
                 operation as bindings::nixl_capi_xfer_op_t,
-                local_descs.handle(),
+                staged.as_ref().unwrap_or(local_descs).handle(),
                 remote_descs.handle(),
                 remote_agent.as_ptr(),
                 &mut req,
@@ -661,15 +1326,427 @@ impl Agent {
             )
         };
 
-        match status {
+        let result = match status {
             NIXL_CAPI_SUCCESS => {
                 // SAFETY: If status is NIXL_CAPI_SUCCESS, req is guaranteed to be non-null
                 let inner = NonNull::new(req).ok_or(NixlError::FailedToCreateXferRequest)?;
-                Ok(XferRequest::new(inner, self.inner.clone()))
+                XferRequest::new(
+                    inner,
+                    self.inner.clone(),
+                    crc,
+                    transform,
+                    layout,
+                    opt_args,
+                    sequenced,
+                )
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
             _ => Err(NixlError::FailedToCreateXferRequest),
+        };
+        self.record(result)
+    }
+
+    /// Validates a transfer and reports what it would do, without posting it
+    ///
+    /// Descriptor counts and lengths, remote regions, operation support and
+    /// backend selection are all checked. Validation failures are collected in
+    /// the report rather than returned as errors. Nothing is registered or
+    /// posted, and no notification sequence number is taken.
+    ///
+    /// # Arguments
+    /// * `operation` - The transfer operation
+    /// * `local_descs` - The local descriptor list
+    /// * `remote_descs` - The remote descriptor list
+    /// * `remote_agent` - The name of the remote agent
+    /// * `opt_args` - Optional arguments for the transfer request
+    pub fn dry_run_xfer(
+        &self,
+        operation: XferOp,
+        local_descs: &XferDescList,
+        remote_descs: &XferDescList,
+        remote_agent: &str,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<XferPlanReport, NixlError> {
+        let descriptor_count = local_descs.len()?;
+        let mut report = XferPlanReport {
+            operation,
+            remote_agent: remote_agent.to_string(),
+            local_mem_type: local_descs.get_type()?,
+            remote_mem_type: remote_descs.get_type()?,
+            descriptor_count,
+            total_bytes: 0,
+            backend: None,
+            problems: Vec::new(),
+        };
+
+        if remote_descs.len()? != descriptor_count {
+            report.problems.push(NixlError::InvalidParam);
+        } else {
+            for index in 0..descriptor_count {
+                let (_, local_len, _) = local_descs.get_desc(index)?;
+                let (_, remote_len, _) = remote_descs.get_desc(index)?;
+                if local_len != remote_len {
+                    report.problems.push(NixlError::InvalidParam);
+                    break;
+                }
+                report.total_bytes += local_len;
+            }
+        }
+
+        if let Err(e) = self.probe_remote(remote_descs, remote_agent) {
+            report.problems.push(e);
+        }
+        let checked =
+            self.inner
+                .read()
+                .unwrap()
+                .check_xfer(operation, local_descs, remote_descs, opt_args);
+        if let Err(e) = checked {
+            report.problems.push(e);
+        }
+
+        let transform = opt_args.and_then(|args| args.transform()).is_some();
+        if transform && report.local_mem_type != MemType::Dram {
+            report.problems.push(NixlError::InvalidParam);
+        }
+
+        // Backend selection is only known to NIXL, so an unposted request is
+        // created to ask it. It carries no notification or transform, so no
+        // scratch buffer is registered for it.
+        if report.is_valid() {
+            let probe_args = match opt_args {
+                Some(args) => {
+                    let mut args = args.copy(None, false)?;
+                    args.transform = None;
+                    Some(args)
+                }
+                None => None,
+            };
+            let backend = self
+                .create_xfer_req(
+                    operation,
+                    local_descs,
+                    remote_descs,
+                    remote_agent,
+                    probe_args.as_ref(),
+                )
+                .and_then(|req| req.selected_backend());
+            match backend {
+                Ok(backend) => report.backend = Some(backend),
+                Err(e) => report.problems.push(e),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Writes descriptors of any size, splitting those the backends cannot take whole
+    ///
+    /// Descriptors longer than the smallest `max_transfer_size` declared for
+    /// the backends capable of the write are divided into chunks within that
+    /// limit. The n-th chunk of every descriptor is posted in the n-th request
+    /// of the returned group, and notification settings from `opt_args` are
+    /// applied to each request.
+    ///
+    /// # Arguments
+    /// * `local_descs` - The local descriptor list
+    /// * `remote_descs` - The remote descriptor list
+    /// * `remote_agent` - The name of the remote agent
+    /// * `opt_args` - Optional arguments for the transfer requests
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if the descriptor lists differ in
+    /// count or lengths
+    pub fn write_large(
+        &self,
+        local_descs: &XferDescList,
+        remote_descs: &XferDescList,
+        remote_agent: &str,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<XferGroup, NixlError> {
+        let count = local_descs.len()?;
+        if remote_descs.len()? != count {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let mem_type = local_descs.get_type()?;
+        let limit = self.inner.read().unwrap().min_transfer_size_limit(mem_type);
+
+        let mut rounds: Vec<(XferDescList, XferDescList)> = Vec::new();
+        for index in 0..count {
+            let (local_addr, len, local_dev) = local_descs.get_desc(index)?;
+            let (remote_addr, remote_len, remote_dev) = remote_descs.get_desc(index)?;
+            if len != remote_len {
+                return Err(NixlError::InvalidParam);
+            }
+
+            let chunk_len = limit.unwrap_or(len).max(1);
+            let mut offset = 0;
+            for round in 0.. {
+                if rounds.len() == round {
+                    rounds.push((
+                        XferDescList::new(mem_type, false)?,
+                        XferDescList::new(remote_descs.get_type()?, false)?,
+                    ));
+                }
+                let chunk = chunk_len.min(len - offset);
+                let (local, remote) = &mut rounds[round];
+                local.add_desc(local_addr + offset, chunk, local_dev)?;
+                remote.add_desc(remote_addr + offset, chunk, remote_dev)?;
+                offset += chunk;
+                if offset >= len {
+                    break;
+                }
+            }
+        }
+
+        let mut group = XferGroup::new(self);
+        for (local, remote) in &rounds {
+            let req = self.create_xfer_req(XferOp::Write, local, remote, remote_agent, opt_args)?;
+            self.post_xfer_req(&req, opt_args)?;
+            group.add(req);
+        }
+        Ok(group)
+    }
+
+    /// Creates and posts a transfer that reads some regions and writes others
+    ///
+    /// NIXL requests carry a single operation, so the parts are combined into
+    /// one request per operation and pair of memory types, and the returned
+    /// group completes once all of them have. Notification settings from
+    /// `opt_args` are applied to each request, so the remote agent receives
+    /// one notification per operation.
+    ///
+    /// # Arguments
+    /// * `parts` - The operation, local descriptors and remote descriptors of each part
+    /// * `remote_agent` - The name of the remote agent
+    /// * `opt_args` - Optional arguments for the transfer requests
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if `parts` is empty
+    pub fn create_mixed_xfer_req(
+        &self,
+        parts: &[(XferOp, &XferDescList, &XferDescList)],
+        remote_agent: &str,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<XferGroup, NixlError> {
+        if parts.is_empty() {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let mut merged: Vec<(XferOp, XferDescList, XferDescList)> = Vec::new();
+        for &(operation, local, remote) in parts {
+            let (local_type, remote_type) = (local.get_type()?, remote.get_type()?);
+            let mut found = None;
+            for (index, (op, merged_local, merged_remote)) in merged.iter().enumerate() {
+                if *op == operation
+                    && merged_local.get_type()? == local_type
+                    && merged_remote.get_type()? == remote_type
+                {
+                    found = Some(index);
+                    break;
+                }
+            }
+            let index = match found {
+                Some(index) => index,
+                None => {
+                    merged.push((
+                        operation,
+                        XferDescList::new(local_type, false)?,
+                        XferDescList::new(remote_type, false)?,
+                    ));
+                    merged.len() - 1
+                }
+            };
+            let (_, merged_local, merged_remote) = &mut merged[index];
+            merged_local.append(local)?;
+            merged_remote.append(remote)?;
+        }
+
+        let mut requests = Vec::with_capacity(merged.len());
+        for (operation, local, remote) in &merged {
+            requests.push(self.create_xfer_req(
+                *operation,
+                local,
+                remote,
+                remote_agent,
+                opt_args,
+            )?);
+        }
+
+        let mut group = XferGroup::new(self);
+        for req in requests {
+            self.post_xfer_req(&req, opt_args)?;
+            group.add(req);
+        }
+        Ok(group)
+    }
+
+    /// Splits a write across several backends and posts one request per backend
+    ///
+    /// Every descriptor pair is divided into contiguous stripes, one per
+    /// backend, so that each backend carries an equal share of every
+    /// descriptor. Every stripe is created with `opt_args` and its own backend,
+    /// but only the last stripe notifies, with the length of the whole write
+    /// if `OptArgs::set_notify_with_length` is set. Backends complete
+    /// independently, so the notification can arrive before the other stripes
+    /// land; wait on the returned group where that matters.
+    ///
+    /// # Arguments
+    /// * `local_descs` - The local descriptor list
+    /// * `remote_descs` - The remote descriptor list
+    /// * `remote_agent` - The name of the remote agent
+    /// * `backends` - Backends to spread the transfer across
+    /// * `opt_args` - Optional arguments for the transfer requests
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if no backends are given, the
+    /// descriptor lists differ in count or lengths, or `opt_args` asks for
+    /// `OptArgs::set_append_crc`, whose per-descriptor CRC a stripe cannot hold
+    pub fn striped_write(
+        &self,
+        local_descs: &XferDescList,
+        remote_descs: &XferDescList,
+        remote_agent: &str,
+        backends: &[&Backend],
+        opt_args: Option<&OptArgs>,
+    ) -> Result<XferGroup, NixlError> {
+        let count = local_descs.len()?;
+        if backends.is_empty()
+            || remote_descs.len()? != count
+            || opt_args.is_some_and(|args| args.append_crc())
+        {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let mut stripes = Vec::with_capacity(backends.len());
+        for _ in backends {
+            stripes.push((
+                XferDescList::new(local_descs.get_type()?, false)?,
+                XferDescList::new(remote_descs.get_type()?, false)?,
+            ));
+        }
+
+        let mut total = 0u64;
+        for index in 0..count {
+            let (local_addr, len, local_dev) = local_descs.get_desc(index)?;
+            let (remote_addr, remote_len, remote_dev) = remote_descs.get_desc(index)?;
+            if len != remote_len {
+                return Err(NixlError::InvalidParam);
+            }
+            total += len as u64;
+
+            let stripe_len = len.div_ceil(backends.len());
+            for (stripe, (local, remote)) in stripes.iter_mut().enumerate() {
+                let offset = stripe * stripe_len;
+                if offset >= len {
+                    break;
+                }
+                let chunk = stripe_len.min(len - offset);
+                local.add_desc(local_addr + offset, chunk, local_dev)?;
+                remote.add_desc(remote_addr + offset, chunk, remote_dev)?;
+            }
+        }
+
+        let mut last = 0;
+        for (stripe, (local, _)) in stripes.iter().enumerate() {
+            if !local.is_empty()? {
+                last = stripe;
+            }
+        }
+
+        let mut group = XferGroup::new(self);
+        for (stripe, (backend, (local, remote))) in backends.iter().zip(&stripes).enumerate() {
+            if local.is_empty()? {
+                continue;
+            }
+
+            let mut stripe_args = match opt_args {
+                Some(args) => args.copy(Some(&[backend.inner]), stripe == last)?,
+                None => {
+                    let mut args = OptArgs::new()?;
+                    args.add_backend(backend)?;
+                    args
+                }
+            };
+            if stripe_args.notify_with_length() {
+                stripe_args = stripe_args.with_notification_prefix(&total.to_le_bytes())?;
+            }
+
+            let req = self.create_xfer_req(
+                XferOp::Write,
+                local,
+                remote,
+                remote_agent,
+                Some(&stripe_args),
+            )?;
+            self.post_xfer_req(&req, None)?;
+            group.add(req);
+        }
+        Ok(group)
+    }
+
+    /// Writes to a remote agent only if a remote guard word holds an expected value
+    ///
+    /// The guard is read first and the write is created, posted and waited on
+    /// only if it matches. The check is optimistic: the guard is not locked, so
+    /// another agent may change it between the read and the write.
+    ///
+    /// # Arguments
+    /// * `remote_guard` - A single 8-byte remote descriptor holding the guard
+    /// * `expected` - The guard value, in native byte order, that allows the write
+    /// * `local_descs` - The local descriptor list to write from
+    /// * `remote_descs` - The remote descriptor list to write to
+    /// * `remote_agent` - The name of the remote agent
+    /// * `opt_args` - Optional arguments for the write
+    ///
+    /// # Returns
+    /// `true` if the guard matched and the write completed, `false` otherwise
+    pub fn write_if_equal(
+        &self,
+        remote_guard: &XferDescList,
+        expected: u64,
+        local_descs: &XferDescList,
+        remote_descs: &XferDescList,
+        remote_agent: &str,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<bool, NixlError> {
+        const GUARD_LEN: usize = size_of::<u64>();
+
+        if remote_guard.len()? != 1 || remote_guard.get_desc(0)?.1 != GUARD_LEN {
+            return self.record(Err(NixlError::InvalidParam));
+        }
+
+        let mut guard = SystemStorage::new(GUARD_LEN)?;
+        guard.register(self, None)?;
+        {
+            let mut local_guard = XferDescList::new(MemType::Dram, false)?;
+            local_guard.add_storage_desc(&guard)?;
+            let req =
+                self.create_xfer_req(XferOp::Read, &local_guard, remote_guard, remote_agent, None)?;
+            self.post_xfer_req(&req, None)?;
+            while self.get_xfer_status(&req)? {
+                std::thread::yield_now();
+            }
+        }
+
+        let value = u64::from_ne_bytes(guard.as_slice().try_into().unwrap());
+        if value != expected {
+            tracing::trace!(value, expected, "Guard mismatch, skipping write");
+            return Ok(false);
+        }
+
+        let req = self.create_xfer_req(
+            XferOp::Write,
+            local_descs,
+            remote_descs,
+            remote_agent,
+            opt_args,
+        )?;
+        self.post_xfer_req(&req, None)?;
+        while self.get_xfer_status(&req)? {
+            std::thread::yield_now();
         }
+        Ok(true)
     }
 
     /// Estimates the cost of a transfer request
@@ -703,11 +1780,34 @@ impl Agent {
             )
         };
 
-        match status {
+        let result = match status {
             NIXL_CAPI_SUCCESS => Ok((duration_us, err_margin_us, CostMethod::from(method))),
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
             _ => Err(NixlError::BackendError),
-        }
+        };
+        self.record(result)
+    }
+
+    /// Describes the size of a transfer request before it is posted
+    ///
+    /// The byte and descriptor counts are computed from the descriptors the
+    /// request was created with. The backend's estimate from
+    /// `estimate_xfer_cost` is included when the backend provides one.
+    pub fn xfer_cost(
+        &self,
+        req: &XferRequest,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<XferCost, NixlError> {
+        let estimate = match self.estimate_xfer_cost(req, opt_args) {
+            Ok(estimate) => Some(estimate),
+            Err(NixlError::BackendError) => None,
+            Err(e) => return Err(e),
+        };
+        Ok(XferCost {
+            total_bytes: req.layout().total_len(),
+            descriptor_count: req.layout().pairs.len(),
+            estimate,
+        })
     }
 
     /// Posts a transfer request to initiate a transfer
@@ -729,41 +1829,191 @@ impl Agent {
         req: &XferRequest,
         opt_args: Option<&OptArgs>,
     ) -> Result<bool, NixlError> {
-        tracing::trace!("Posting transfer request");
-        let status = unsafe {
-            nixl_capi_post_xfer_req(
-                self.inner.write().unwrap().handle.as_ptr(),
-                req.handle(),
-                opt_args.map_or(ptr::null_mut(), |args| args.inner.as_ptr()),
-            )
+        let result = self.inner.write().unwrap().post(req, opt_args);
+        self.record(result)
+    }
+
+    /// Posts a transfer request and blocks until it completes, reporting progress
+    ///
+    /// `progress` receives `(bytes_done, bytes_total)`, first with nothing
+    /// done and then each time part of the transfer completes. NIXL reports
+    /// completion per request, so a request with several descriptors is split
+    /// into one sub-request per descriptor, and the callback resolution is one
+    /// descriptor. The sub-requests are created with the arguments `req` was
+    /// created with, and `req` itself is not posted and stays reusable. The
+    /// notification, from `opt_args` or else from `req`, goes on the last
+    /// sub-request, which is posted once the others have completed.
+    ///
+    /// Requests with a single descriptor, or created with
+    /// `OptArgs::set_append_crc` or `OptArgs::set_transform`, are posted
+    /// whole and report progress only on completion.
+    ///
+    /// # Arguments
+    /// * `req` - Transfer request handle obtained from `create_xfer_req`
+    /// * `opt_args` - Optional arguments for the transfer request
+    /// * `progress` - Callback receiving the bytes done and the total bytes
+    pub fn post_xfer_req_with_progress(
+        &self,
+        req: &XferRequest,
+        opt_args: Option<&OptArgs>,
+        mut progress: impl FnMut(usize, usize),
+    ) -> Result<(), NixlError> {
+        let layout = req.layout();
+        let total = layout.total_len();
+        progress(0, total);
+
+        if layout.pairs.len() <= 1 || req.crc().is_some() || req.transform().is_some() {
+            if self.post_xfer_req(req, opt_args)? {
+                while self.get_xfer_status(req)? {
+                    std::thread::yield_now();
+                }
+            }
+            progress(total, total);
+            return Ok(());
+        }
+
+        let last_args = match req.notification_for(opt_args)? {
+            Some(message) => {
+                let mut args = match req.args() {
+                    Some(args) => args.copy(None, false)?,
+                    None => OptArgs::new()?,
+                };
+                args.set_has_notification(true)?;
+                args.set_notification_message(&message)?;
+                args.set_notify_with_sequence(req.sequenced_for(opt_args));
+                Some(args)
+            }
+            None => None,
         };
 
-        match status {
-            NIXL_CAPI_SUCCESS => {
-                tracing::trace!(
-                    status = "completed",
-                    "Transfer request completed immediately"
-                );
-                Ok(false)
+        let mut pending = Vec::with_capacity(layout.pairs.len());
+        let mut last = None;
+        let mut failure = None;
+        for (index, (local_desc, remote_desc)) in layout.pairs.iter().enumerate() {
+            let notifies = index + 1 == layout.pairs.len() && last_args.is_some();
+            let args = if notifies {
+                last_args.as_ref()
+            } else {
+                req.args()
+            };
+            let sub = self.create_sub_xfer_req(layout, local_desc, remote_desc, args);
+            let sub = match sub {
+                Ok(sub) if !notifies => self.post_xfer_req(&sub, None).map(|_| sub),
+                other => other,
+            };
+            match sub {
+                Ok(sub) if notifies => last = Some((sub, local_desc.len)),
+                Ok(sub) => pending.push((sub, local_desc.len)),
+                Err(err) => {
+                    failure = Some(err);
+                    break;
+                }
             }
-            NIXL_CAPI_IN_PROG => {
-                tracing::trace!(status = "in_progress", "Transfer request in progress");
-                Ok(true)
+        }
+
+        // The notifying sub-request is posted once the others are done, so
+        // the notification cannot reach the peer ahead of any of the data.
+        let mut done = 0;
+        while failure.is_none() && (!pending.is_empty() || last.is_some()) {
+            if pending.is_empty() {
+                if let Some((sub, len)) = last.take() {
+                    match self.post_xfer_req(&sub, None) {
+                        Ok(_) => pending.push((sub, len)),
+                        Err(err) => failure = Some(err),
+                    }
+                }
+                continue;
             }
-            NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(error = "invalid_param", "Failed to post transfer request");
-                Err(NixlError::InvalidParam)
+            let mut completed = false;
+            for index in (0..pending.len()).rev() {
+                match self.get_xfer_status(&pending[index].0) {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        done += pending.swap_remove(index).1;
+                        completed = true;
+                    }
+                    Err(err) => {
+                        failure = Some(err);
+                        break;
+                    }
+                }
             }
-            _ => {
-                tracing::error!(error = "backend_error", "Failed to post transfer request");
-                Err(NixlError::BackendError)
+            if completed {
+                progress(done, total);
+            } else {
+                std::thread::yield_now();
+            }
+        }
+
+        match failure {
+            Some(err) => {
+                // Sub-requests already posted still write into the caller's
+                // buffers, so wait for them before handing the error back.
+                while pending
+                    .iter()
+                    .any(|(sub, _)| self.get_xfer_status(sub).unwrap_or(false))
+                {
+                    std::thread::yield_now();
+                }
+                Err(err)
             }
+            None => Ok(()),
         }
     }
 
+    /// Creates a request moving one descriptor pair of `layout`
+    fn create_sub_xfer_req(
+        &self,
+        layout: &XferLayout,
+        local_desc: &Desc,
+        remote_desc: &Desc,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<XferRequest, NixlError> {
+        let mut local = XferDescList::new(layout.local_type, false)?;
+        local.add_desc(local_desc.addr, local_desc.len, local_desc.dev_id)?;
+        let mut remote = XferDescList::new(layout.remote_type, false)?;
+        remote.add_desc(remote_desc.addr, remote_desc.len, remote_desc.dev_id)?;
+        self.create_xfer_req(
+            layout.operation,
+            &local,
+            &remote,
+            &layout.remote_agent,
+            opt_args,
+        )
+    }
+
+    /// Posts several transfer requests together
+    ///
+    /// NIXL backends take one request per submission, so the requests are
+    /// posted one after another while the agent's bookkeeping lock is held
+    /// once for the whole batch. If a post fails, the error is returned and
+    /// requests posted before it keep running.
+    ///
+    /// # Arguments
+    /// * `reqs` - Transfer requests obtained from `create_xfer_req`
+    /// * `opt_args` - Optional arguments applied to every request
+    pub fn post_batch<'a>(
+        &self,
+        reqs: &[&'a XferRequest],
+        opt_args: Option<&OptArgs>,
+    ) -> Result<BatchHandle<'a>, NixlError> {
+        let mut inner = self.inner.write().unwrap();
+        let pending = reqs
+            .iter()
+            .map(|req| inner.post(req, opt_args))
+            .collect::<Result<Vec<_>, _>>();
+        drop(inner);
+        let pending = self.record(pending)?;
+        Ok(BatchHandle::new(self, reqs.to_vec(), pending))
+    }
+
     /// Checks the status of a transfer request
     ///
     /// Returns `Ok(true)` if the transfer is still in progress, `Ok(false)` if it completed successfully.
+    /// Reads created with `OptArgs::set_append_crc` return `NixlError::CrcMismatch`
+    /// on completion if a payload does not match its CRC. Transformed data is
+    /// decoded and CRCs are checked only on the first poll that sees the
+    /// completion; later polls return the same result.
     ///
     /// # Arguments
     /// * `req` - Transfer request handle after `post_xfer_req`
@@ -772,38 +2022,465 @@ impl Agent {
             nixl_capi_get_xfer_status(self.inner.write().unwrap().handle.as_ptr(), req.handle())
         };
 
-        match status {
-            NIXL_CAPI_SUCCESS => Ok(false), // Transfer completed
-            NIXL_CAPI_IN_PROG => Ok(true),  // Transfer in progress
+        let result = match status {
+            // Transfer completed
+            NIXL_CAPI_SUCCESS => req.complete().map(|()| false),
+            NIXL_CAPI_IN_PROG => Ok(true), // Transfer in progress
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
             _ => Err(NixlError::BackendError),
+        };
+        self.record(result)
+    }
+
+    /// Returns the state of a transfer request
+    ///
+    /// This is `get_xfer_status` as an enum: `Ok(true)` there is
+    /// `XferState::InProgress` here and `Ok(false)` is `XferState::Done`. A
+    /// backend failure of the transfer is reported as `XferState::Error`
+    /// rather than as an `Err`.
+    ///
+    /// # Arguments
+    /// * `req` - Transfer request handle obtained from `create_xfer_req`
+    pub fn xfer_state(&self, req: &XferRequest) -> Result<XferState, NixlError> {
+        if !req.is_posted() {
+            return Ok(XferState::NotPosted);
+        }
+        match self.get_xfer_status(req) {
+            Ok(true) => Ok(XferState::InProgress),
+            Ok(false) => Ok(XferState::Done),
+            Err(NixlError::BackendError) => Ok(XferState::Error),
+            Err(e) => Err(e),
         }
     }
 
-    /// Gets notifications from other agents
+    /// Returns a future that resolves once a posted transfer request completes
+    ///
+    /// Dropping the future before it resolves does not cancel the transfer.
     ///
     /// # Arguments
-    /// * `notifs` - Notification map to populate with notifications
-    /// * `opt_args` - Optional arguments to filter notifications by backend
-    pub fn get_notifications(
+    /// * `req` - Transfer request handle after `post_xfer_req`
+    pub fn xfer_completion<'a>(&self, req: &'a XferRequest) -> XferFuture<'a> {
+        XferFuture::new(self, req)
+    }
+
+    /// Performs a transfer and blocks until it completes
+    ///
+    /// Creates the request, posts it and polls its status, for callers that
+    /// do not need the request handle.
+    ///
+    /// # Arguments
+    /// * `operation` - Whether to read from or write to the remote agent
+    /// * `local_descs` - The local descriptor list
+    /// * `remote_descs` - The remote descriptor list
+    /// * `remote_agent` - The name of the remote agent
+    /// * `notify` - Optional notification message sent to the remote agent on completion
+    pub fn transfer(
         &self,
-        notifs: &mut NotificationMap,
-        opt_args: Option<&OptArgs>,
+        operation: XferOp,
+        local_descs: &XferDescList,
+        remote_descs: &XferDescList,
+        remote_agent: &str,
+        notify: Option<&[u8]>,
     ) -> Result<(), NixlError> {
-        tracing::trace!("Getting notifications");
-        let status = unsafe {
-            nixl_capi_get_notifs(
-                self.inner.write().unwrap().handle.as_ptr(),
-                notifs.inner.as_ptr(),
-                opt_args.map_or(ptr::null_mut(), |args| args.inner.as_ptr()),
-            )
+        let opt_args = match notify {
+            Some(message) => {
+                let mut args = OptArgs::new()?;
+                args.set_has_notification(true)?;
+                args.set_notification_message(message)?;
+                Some(args)
+            }
+            None => None,
         };
 
-        match status {
-            NIXL_CAPI_SUCCESS => {
-                tracing::trace!("Successfully retrieved notifications");
-                Ok(())
+        let req = self.create_xfer_req(
+            operation,
+            local_descs,
+            remote_descs,
+            remote_agent,
+            opt_args.as_ref(),
+        )?;
+        if self.post_xfer_req(&req, opt_args.as_ref())? {
+            while self.get_xfer_status(&req)? {
+                std::thread::yield_now();
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates and posts a transfer that takes ownership of its descriptor lists
+    ///
+    /// The returned future resolves to the lists once the transfer completes,
+    /// so per-request lists can be recycled without borrowing across awaits.
+    ///
+    /// # Arguments
+    /// * `operation` - Whether to read from or write to the remote agent
+    /// * `local_descs` - The local descriptor list
+    /// * `remote_descs` - The remote descriptor list
+    /// * `remote_agent` - The name of the remote agent
+    /// * `opt_args` - Optional arguments for creating and posting the request
+    pub fn post_owned<'a>(
+        &self,
+        operation: XferOp,
+        local_descs: XferDescList<'a>,
+        remote_descs: XferDescList<'a>,
+        remote_agent: &str,
+        opt_args: Option<&OptArgs>,
+    ) -> OwnedXferFuture<'a> {
+        let state = self
+            .create_xfer_req(
+                operation,
+                &local_descs,
+                &remote_descs,
+                remote_agent,
+                opt_args,
+            )
+            .and_then(|req| {
+                self.post_xfer_req(&req, opt_args)?;
+                Ok((req, local_descs, remote_descs))
+            });
+        OwnedXferFuture::new(self, state)
+    }
+
+    /// Waits up to `timeout` for a posted transfer to complete
+    ///
+    /// The status is polled with exponential backoff, so short transfers
+    /// return quickly while long ones do not keep a core busy.
+    ///
+    /// # Arguments
+    /// * `req` - Transfer request handle after `post_xfer_req`
+    /// * `timeout` - Maximum time to wait for completion; `Duration::MAX` waits indefinitely
+    ///
+    /// # Returns
+    /// `true` if the transfer completed, `false` if it was still in progress at the timeout
+    pub fn wait_xfer(&self, req: &XferRequest, timeout: Duration) -> Result<bool, NixlError> {
+        // A timeout too long to represent waits without a deadline
+        let deadline = Instant::now().checked_add(timeout);
+        let mut backoff = WAIT_BACKOFF_MIN;
+        while self.get_xfer_status(req)? {
+            let mut sleep = backoff;
+            if let Some(deadline) = deadline {
+                let now = Instant::now();
+                if now >= deadline {
+                    return Ok(false);
+                }
+                sleep = sleep.min(deadline - now);
+            }
+            std::thread::sleep(sleep);
+            backoff = (backoff * 2).min(WAIT_BACKOFF_MAX);
+        }
+        Ok(true)
+    }
+
+    /// Waits for a posted transfer to complete, logging its progress
+    ///
+    /// An `info` event with the elapsed time is emitted every `log_interval`
+    /// while the transfer is in progress, and once more on completion. The
+    /// status is polled with the same backoff as `wait_xfer`.
+    ///
+    /// # Arguments
+    /// * `req` - Transfer request handle after `post_xfer_req`
+    /// * `log_interval` - Time between progress events
+    /// * `timeout` - Maximum time to wait for completion
+    ///
+    /// # Errors
+    /// Returns `NixlError::Timeout` if the transfer is still in progress after `timeout`
+    pub fn wait_with_logging(
+        &self,
+        req: &XferRequest,
+        log_interval: Duration,
+        timeout: Duration,
+    ) -> Result<(), NixlError> {
+        let start = Instant::now();
+        let mut last_log = start;
+        let mut backoff = WAIT_BACKOFF_MIN;
+        while self.get_xfer_status(req)? {
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                tracing::error!(elapsed_ms = elapsed.as_millis(), "Transfer timed out");
+                return self.record(Err(NixlError::Timeout));
+            }
+            if last_log.elapsed() >= log_interval {
+                tracing::info!(elapsed_ms = elapsed.as_millis(), "Transfer in progress");
+                last_log = Instant::now();
+            }
+            // Wake up in time for the next event and for the timeout
+            let until_log = log_interval.saturating_sub(last_log.elapsed());
+            std::thread::sleep(backoff.min(until_log).min(timeout - elapsed));
+            backoff = (backoff * 2).min(WAIT_BACKOFF_MAX);
+        }
+
+        tracing::info!(
+            elapsed_ms = start.elapsed().as_millis(),
+            "Transfer completed"
+        );
+        Ok(())
+    }
+
+    /// Measures the latency of notification round trips to a remote agent
+    ///
+    /// Each iteration sends a ping notification and waits for the remote
+    /// agent to echo it, so the remote agent must have this agent's metadata
+    /// loaded and call `answer_pings` while this runs. Other notifications
+    /// received meanwhile are kept for `get_notifications`. Use `ping_xfer`
+    /// to include a write in each trip.
+    ///
+    /// # Arguments
+    /// * `remote_agent` - The name of the remote agent
+    /// * `iterations` - Number of round trips to perform
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if `iterations` is zero, and
+    /// `NixlError::Timeout` if a ping is not echoed within 10 seconds
+    pub fn ping(&self, remote_agent: &str, iterations: usize) -> Result<LatencyStats, NixlError> {
+        if iterations == 0 {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let mut samples = Vec::with_capacity(iterations);
+        for id in 0..iterations as u64 {
+            let start = Instant::now();
+            self.send_notification(
+                remote_agent,
+                &[PING_PREFIX, &id.to_le_bytes()].concat(),
+                None,
+            )?;
+            self.await_pong(remote_agent, id)?;
+            samples.push(start.elapsed());
+        }
+        Ok(LatencyStats::from_samples(samples))
+    }
+
+    /// Measures the latency of write round trips to a remote agent
+    ///
+    /// Like `ping`, but each iteration writes `local_descs` to `remote_descs`
+    /// with the ping as the write's notification, so a trip covers the write
+    /// and the echo.
+    ///
+    /// # Arguments
+    /// * `remote_agent` - The name of the remote agent
+    /// * `local_descs` - The local descriptor list
+    /// * `remote_descs` - The remote descriptor list
+    /// * `iterations` - Number of round trips to perform
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if `iterations` is zero, and
+    /// `NixlError::Timeout` if a ping is not echoed within 10 seconds
+    pub fn ping_xfer(
+        &self,
+        remote_agent: &str,
+        local_descs: &XferDescList,
+        remote_descs: &XferDescList,
+        iterations: usize,
+    ) -> Result<LatencyStats, NixlError> {
+        if iterations == 0 {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let req =
+            self.create_xfer_req(XferOp::Write, local_descs, remote_descs, remote_agent, None)?;
+        let mut samples = Vec::with_capacity(iterations);
+        for id in 0..iterations as u64 {
+            let mut opt_args = OptArgs::new()?;
+            opt_args.set_has_notification(true)?;
+            opt_args.set_notification_message(&[PING_PREFIX, &id.to_le_bytes()].concat())?;
+
+            let start = Instant::now();
+            let mut in_progress = self.post_xfer_req(&req, Some(&opt_args))?;
+            while in_progress {
+                std::thread::yield_now();
+                in_progress = self.get_xfer_status(&req)?;
+            }
+            self.await_pong(remote_agent, id)?;
+            samples.push(start.elapsed());
+        }
+        Ok(LatencyStats::from_samples(samples))
+    }
+
+    /// Echoes the pings received from other agents and returns how many there were
+    ///
+    /// An agent that is pinged with `ping` or `ping_xfer` calls this while the
+    /// pings run. Other notifications are kept for `get_notifications`.
+    pub fn answer_pings(&self) -> Result<usize, NixlError> {
+        let pings = {
+            let mut inner = self.inner.write().unwrap();
+            let result = inner.receive_pending_notifs();
+            self.record(result)?;
+            let mut pings = Vec::new();
+            inner.pending_notifs.retain(|(_, agent, message)| {
+                match message.strip_prefix(PING_PREFIX) {
+                    Some(id) if id.len() == 8 => {
+                        pings.push((agent.clone(), [PONG_PREFIX, id].concat()));
+                        false
+                    }
+                    _ => true,
+                }
+            });
+            pings
+        };
+
+        for (agent, pong) in &pings {
+            self.send_notification(agent, pong, None)?;
+        }
+        Ok(pings.len())
+    }
+
+    /// Takes the queued notifications from `remote_agent` that `select`
+    /// accepts, oldest first, leaving every other notification queued
+    pub(crate) fn take_notifications_from(
+        &self,
+        remote_agent: &str,
+        select: impl Fn(&[u8]) -> bool,
+    ) -> Result<Vec<Vec<u8>>, NixlError> {
+        let mut inner = self.inner.write().unwrap();
+        let result = inner.receive_pending_notifs();
+        self.record(result)?;
+        let mut taken = Vec::new();
+        inner.pending_notifs.retain(|(_, agent, message)| {
+            if agent == remote_agent && select(message) {
+                taken.push(message.clone());
+                false
+            } else {
+                true
+            }
+        });
+        Ok(taken)
+    }
+
+    /// Waits for `remote_agent` to echo the ping with the given id
+    fn await_pong(&self, remote_agent: &str, id: u64) -> Result<(), NixlError> {
+        let pong = [PONG_PREFIX, &id.to_le_bytes()].concat();
+        let deadline = Instant::now() + PING_TIMEOUT;
+        loop {
+            {
+                let mut inner = self.inner.write().unwrap();
+                let result = inner.receive_pending_notifs();
+                self.record(result)?;
+                let echoed = inner
+                    .pending_notifs
+                    .iter()
+                    .position(|(_, agent, message)| agent == remote_agent && *message == pong);
+                if let Some(index) = echoed {
+                    inner.pending_notifs.remove(index);
+                    return Ok(());
+                }
+            }
+            if Instant::now() >= deadline {
+                return self.record(Err(NixlError::Timeout));
+            }
+            std::thread::yield_now();
+        }
+    }
+
+    /// Drives backend progress so pending notifications are sent without delay
+    ///
+    /// Backends only send queued notifications when they make progress, which
+    /// otherwise happens the next time the agent is polled. Notifications
+    /// received while flushing are kept and returned by the next call to
+    /// `get_notifications`.
+    ///
+    /// # Arguments
+    /// * `remote_name` - Optional remote agent whose metadata must be loaded.
+    ///   Progress is made per backend, so notifications to every agent are flushed.
+    pub fn flush_notifications(&self, remote_name: Option<&str>) -> Result<(), NixlError> {
+        if let Some(remote) = remote_name {
+            if !self.check_remote_metadata(remote, None) {
+                return self.record(Err(NixlError::InvalidParam));
             }
+        }
+
+        let result = self.inner.write().unwrap().receive_pending_notifs();
+        self.record(result)
+    }
+
+    /// Returns a stream yielding each notification once it is retrieved
+    ///
+    /// Notifications are taken from the agent one at a time, so none are lost
+    /// between polls or when the stream is dropped.
+    pub fn notification_stream(&self) -> NotificationStream {
+        NotificationStream::new(self)
+    }
+
+    /// Takes the oldest notification, fetching new ones when none are queued
+    pub(crate) fn next_notification(&self) -> Result<Option<(String, Vec<u8>)>, NixlError> {
+        let mut inner = self.inner.write().unwrap();
+        if inner.pending_notifs.is_empty() {
+            let result = inner.receive_pending_notifs();
+            self.record(result)?;
+        }
+        Ok(inner
+            .pending_notifs
+            .pop_front()
+            .map(|(_, agent, message)| (agent, message)))
+    }
+
+    /// Discards every pending notification and returns how many there were
+    ///
+    /// Meant for counting and barrier patterns where the messages themselves
+    /// are not needed; no `NotificationMap` is allocated on the Rust side.
+    pub fn count_notifications(&self) -> Result<usize, NixlError> {
+        let result = self.inner.write().unwrap().discard_notifs();
+        self.record(result)
+    }
+
+    /// Gets at most `max` notifications from other agents
+    ///
+    /// Notifications beyond the limit are kept for the next call to this
+    /// method or to `get_notifications`, in the order they were retrieved:
+    /// each sender's in arrival order, and senders of the same retrieval in no
+    /// particular order. This keeps a busy sender from starving the rest of an
+    /// event loop.
+    ///
+    /// # Arguments
+    /// * `max` - The maximum number of notifications to add to `notifs`
+    /// * `notifs` - Notification map to populate with notifications
+    pub fn get_notifications_limited(
+        &self,
+        max: usize,
+        notifs: &mut NotificationMap,
+    ) -> Result<(), NixlError> {
+        tracing::trace!(max, "Getting limited notifications");
+        let mut inner = self.inner.write().unwrap();
+        let received = if inner.pending_notifs.len() < max {
+            inner.receive_pending_notifs()
+        } else {
+            Ok(())
+        };
+        let result = received.and_then(|()| inner.deliver_pending_notifs(notifs, max));
+        self.record(result)
+    }
+
+    /// Gets notifications from other agents
+    ///
+    /// Notifications from one agent are added in the order they arrive, and
+    /// all of them are stamped with the time of this call. Use
+    /// `OptArgs::set_notify_with_sequence` when the post order must be recovered.
+    ///
+    /// # Arguments
+    /// * `notifs` - Notification map to populate with notifications
+    /// * `opt_args` - Optional arguments to filter notifications by backend
+    pub fn get_notifications(
+        &self,
+        notifs: &mut NotificationMap,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<(), NixlError> {
+        tracing::trace!("Getting notifications");
+        let mut inner = self.inner.write().unwrap();
+        if let Err(e) = inner.deliver_pending_notifs(notifs, usize::MAX) {
+            return self.record(Err(e));
+        }
+        let status = unsafe {
+            nixl_capi_get_notifs(
+                inner.handle.as_ptr(),
+                notifs.inner.as_ptr(),
+                opt_args.map_or(ptr::null_mut(), |args| args.inner.as_ptr()),
+            )
+        };
+
+        let result = match status {
+            NIXL_CAPI_SUCCESS => inner
+                .deliver_self_notifs(notifs)
+                .and_then(|()| notifs.stamp_all_arrivals(Instant::now()))
+                .inspect(|()| tracing::trace!("Successfully retrieved notifications")),
             NIXL_CAPI_ERROR_INVALID_PARAM => {
                 tracing::error!(error = "invalid_param", "Failed to get notifications");
                 Err(NixlError::InvalidParam)
@@ -812,17 +2489,60 @@ impl Agent {
                 tracing::error!(error = "backend_error", "Failed to get notifications");
                 Err(NixlError::BackendError)
             }
-        }
+        };
+        self.record(result)
+    }
+}
+
+/// Reads a limit the caller declared in the parameters of a backend
+fn declared_limit(params: &utils::Params, key: &str) -> Result<Option<usize>, NixlError> {
+    match params.get(key)? {
+        Some(value) => value.parse().map(Some).map_err(|_| NixlError::InvalidParam),
+        None => Ok(None),
     }
 }
 
+/// Capabilities of a backend, read once when it is created
+#[derive(Debug, Clone)]
+pub(crate) struct BackendCaps {
+    /// Memory types the backend registers
+    pub(crate) mem_types: HashSet<MemType>,
+    /// Largest single descriptor declared through `MAX_TRANSFER_SIZE_PARAM`
+    pub(crate) max_transfer_size: Option<usize>,
+    /// Queue capacity hint declared through `QUEUE_CAPACITY_PARAM`
+    pub(crate) queue_capacity: Option<usize>,
+    /// Descriptor count limit declared through `MAX_DESCRIPTORS_PARAM`
+    pub(crate) max_descriptors: Option<usize>,
+}
+
 /// Inner state for an agent that manages the raw pointer
 #[derive(Debug)]
 pub(crate) struct AgentInner {
     pub(crate) name: String,
     pub(crate) handle: NonNull<bindings::nixl_capi_agent_s>,
     pub(crate) backends: HashMap<String, NonNull<bindings::nixl_capi_backend_s>>,
+    /// Capabilities of each backend in `backends`
+    pub(crate) backend_caps: HashMap<NonNull<bindings::nixl_capi_backend_s>, BackendCaps>,
     pub(crate) remotes: HashSet<String>,
+    /// Remote agents whose metadata was invalidated and not loaded again
+    pub(crate) invalidated: HashSet<String>,
+    /// Next notification sequence number per remote agent
+    pub(crate) notification_seqs: HashMap<String, u64>,
+    /// Posted requests that notify this agent itself on completion
+    pub(crate) self_notifs: Vec<(*mut bindings::nixl_capi_xfer_req_s, Vec<u8>)>,
+    /// Backend used when no optional arguments are given
+    pub(crate) default_backend: Option<NonNull<bindings::nixl_capi_backend_s>>,
+    /// Notifications received but not yet handed out, oldest first
+    pub(crate) pending_notifs: VecDeque<(Instant, String, Vec<u8>)>,
+    /// Regions registered through this agent's handles
+    pub(crate) regions: Vec<RegionInfo>,
+    /// Posted requests that were still in progress when last checked
+    pub(crate) posted: Vec<*mut bindings::nixl_capi_xfer_req_s>,
+    /// Requests dropped while in progress, released once they finish, with
+    /// the transform plan whose scratch buffer they still use
+    pub(crate) deferred: Vec<(*mut bindings::nixl_capi_xfer_req_s, Option<TransformPlan>)>,
+    /// Address of the listener thread, if one was started
+    pub(crate) listen_addr: Option<SocketAddr>,
 }
 
 unsafe impl Send for AgentInner {}
@@ -834,29 +2554,523 @@ impl AgentInner {
             name,
             handle,
             backends: HashMap::new(),
+            backend_caps: HashMap::new(),
             remotes: HashSet::new(),
+            invalidated: HashSet::new(),
+            notification_seqs: HashMap::new(),
+            self_notifs: Vec::new(),
+            default_backend: None,
+            pending_notifs: VecDeque::new(),
+            regions: Vec::new(),
+            posted: Vec::new(),
+            deferred: Vec::new(),
+            listen_addr: None,
         }
     }
 
+    /// Posts a transfer request and records it for completion tracking
+    fn post(&mut self, req: &XferRequest, opt_args: Option<&OptArgs>) -> Result<bool, NixlError> {
+        tracing::trace!("Posting transfer request");
+        if let Some(crc) = req.crc() {
+            crc.append();
+        }
+        if let Some(transform) = req.transform() {
+            transform.encode()?;
+        }
+
+        let remote_agent = &req.layout().remote_agent;
+        let seq = self
+            .notification_seqs
+            .get(remote_agent)
+            .copied()
+            .unwrap_or(0);
+        let sequenced_args = match req.notification_for(opt_args)? {
+            Some(body) if req.sequenced_for(opt_args) => {
+                let mut args = match opt_args {
+                    Some(args) => args.copy(None, true)?,
+                    None => OptArgs::new()?,
+                };
+                let mut message = seq.to_le_bytes().to_vec();
+                message.extend(body);
+                args.set_has_notification(true)?;
+                args.set_notification_message(&message)?;
+                Some(args)
+            }
+            _ => None,
+        };
+        let opt_args = sequenced_args.as_ref().or(opt_args);
+
+        let status = unsafe {
+            nixl_capi_post_xfer_req(
+                self.handle.as_ptr(),
+                req.handle(),
+                opt_args.map_or(ptr::null_mut(), |args| args.inner.as_ptr()),
+            )
+        };
+
+        if status == NIXL_CAPI_SUCCESS || status == NIXL_CAPI_IN_PROG {
+            req.mark_posted();
+            if sequenced_args.is_some() {
+                self.notification_seqs.insert(remote_agent.clone(), seq + 1);
+            }
+            if let Some(message) = opt_args.and_then(|args| args.self_notification()) {
+                self.self_notifs.push((req.handle(), message.to_vec()));
+            }
+        }
+        if status == NIXL_CAPI_IN_PROG && !self.posted.contains(&req.handle()) {
+            self.posted.push(req.handle());
+        }
+
+        match status {
+            NIXL_CAPI_SUCCESS => {
+                tracing::trace!(
+                    status = "completed",
+                    "Transfer request completed immediately"
+                );
+                Ok(false)
+            }
+            NIXL_CAPI_IN_PROG => {
+                tracing::trace!(status = "in_progress", "Transfer request in progress");
+                Ok(true)
+            }
+            NIXL_CAPI_ERROR_INVALID_PARAM => {
+                tracing::error!(error = "invalid_param", "Failed to post transfer request");
+                Err(NixlError::InvalidParam)
+            }
+            _ => {
+                tracing::error!(error = "backend_error", "Failed to post transfer request");
+                Err(NixlError::BackendError)
+            }
+        }
+    }
+
+    /// Forgets a region after it was deregistered
+    pub(crate) fn remove_region(&mut self, region: &RegionInfo) {
+        if let Some(index) = self.regions.iter().position(|r| r == region) {
+            self.regions.swap_remove(index);
+        }
+    }
+
+    /// Queues every notification the agent has received, including self notifications
+    fn receive_pending_notifs(&mut self) -> Result<(), NixlError> {
+        let mut received = NotificationMap::new()?;
+        let status = unsafe {
+            nixl_capi_get_notifs(
+                self.handle.as_ptr(),
+                received.inner.as_ptr(),
+                ptr::null_mut(),
+            )
+        };
+        match status {
+            NIXL_CAPI_SUCCESS => {}
+            NIXL_CAPI_ERROR_INVALID_PARAM => return Err(NixlError::InvalidParam),
+            _ => return Err(NixlError::BackendError),
+        }
+        self.deliver_self_notifs(&mut received)?;
+
+        let now = Instant::now();
+        for agent in received.agents() {
+            let agent = agent?;
+            for message in received.peek(agent)?.unwrap_or_default() {
+                self.pending_notifs
+                    .push_back((now, agent.to_string(), message.to_vec()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves up to `max` queued notifications into `notifs`
+    fn deliver_pending_notifs(
+        &mut self,
+        notifs: &mut NotificationMap,
+        max: usize,
+    ) -> Result<(), NixlError> {
+        let count = max.min(self.pending_notifs.len());
+        for (at, agent, message) in self.pending_notifs.drain(..count) {
+            let c_agent = CString::new(agent.as_str())?;
+            let status = unsafe {
+                nixl_capi_notif_map_add_notif(
+                    notifs.inner.as_ptr(),
+                    c_agent.as_ptr(),
+                    message.as_ptr().cast(),
+                    message.len(),
+                )
+            };
+            if status != NIXL_CAPI_SUCCESS {
+                return Err(NixlError::BackendError);
+            }
+            notifs.stamp_arrivals(&agent, at)?;
+        }
+        Ok(())
+    }
+
+    /// Moves self notifications of completed requests into `notifs`
+    fn deliver_self_notifs(&mut self, notifs: &mut NotificationMap) -> Result<(), NixlError> {
+        let messages = self.take_completed_self_notifs();
+        if messages.is_empty() {
+            return Ok(());
+        }
+
+        let name = CString::new(self.name.as_str())?;
+        for message in messages {
+            let status = unsafe {
+                nixl_capi_notif_map_add_notif(
+                    notifs.inner.as_ptr(),
+                    name.as_ptr(),
+                    message.as_ptr().cast(),
+                    message.len(),
+                )
+            };
+            if status != NIXL_CAPI_SUCCESS {
+                return Err(NixlError::BackendError);
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the self notifications of finished requests, returning those that succeeded
+    fn take_completed_self_notifs(&mut self) -> Vec<Vec<u8>> {
+        let handle = self.handle;
+        let mut completed = Vec::new();
+        self.self_notifs.retain_mut(|(req, message)| {
+            let status = unsafe { nixl_capi_get_xfer_status(handle.as_ptr(), *req) };
+            match status {
+                NIXL_CAPI_IN_PROG => true,
+                NIXL_CAPI_SUCCESS => {
+                    completed.push(std::mem::take(message));
+                    false
+                }
+                _ => {
+                    tracing::error!(error = "backend_error", "Self notifying transfer failed");
+                    false
+                }
+            }
+        });
+        completed
+    }
+
+    /// Discards every received notification, returning how many there were
+    fn discard_notifs(&mut self) -> Result<usize, NixlError> {
+        let mut count = 0;
+        let status = unsafe { nixl_capi_count_notifs(self.handle.as_ptr(), &mut count) };
+        match status {
+            NIXL_CAPI_SUCCESS => {}
+            NIXL_CAPI_ERROR_INVALID_PARAM => return Err(NixlError::InvalidParam),
+            _ => return Err(NixlError::BackendError),
+        }
+
+        count += self.take_completed_self_notifs().len();
+        count += self.pending_notifs.len();
+        self.pending_notifs.clear();
+        Ok(count)
+    }
+
     fn get_backend(&self, name: &str) -> Option<NonNull<bindings::nixl_capi_backend_s>> {
         self.backends.get(name).cloned()
     }
 
-    fn invalidate_remote_md(&mut self, remote_agent: &str) -> Result<(), NixlError> {
+    fn backend_params(
+        &self,
+        backend: NonNull<bindings::nixl_capi_backend_s>,
+    ) -> Result<(MemList, utils::Params), NixlError> {
+        let mut mem_list = ptr::null_mut();
+        let mut params = ptr::null_mut();
+
+        let status = unsafe {
+            nixl_capi_get_backend_params(
+                self.handle.as_ptr(),
+                backend.as_ptr(),
+                &mut mem_list,
+                &mut params,
+            )
+        };
+
+        if status != NIXL_CAPI_SUCCESS {
+            return Err(NixlError::BackendError);
+        }
+
+        // SAFETY: If status is NIXL_CAPI_SUCCESS, both pointers are non-null
         unsafe {
-            if self.remotes.remove(remote_agent) {
-                nixl_capi_invalidate_remote_md(self.handle.as_ptr(), remote_agent.as_ptr().cast());
-            } else {
-                return Err(NixlError::InvalidParam);
+            Ok((
+                MemList {
+                    inner: NonNull::new_unchecked(mem_list),
+                },
+                utils::Params::new(NonNull::new_unchecked(params)),
+            ))
+        }
+    }
+
+    fn backend_caps(
+        &self,
+        backend: NonNull<bindings::nixl_capi_backend_s>,
+    ) -> Result<&BackendCaps, NixlError> {
+        self.backend_caps
+            .get(&backend)
+            .ok_or(NixlError::InvalidParam)
+    }
+
+    pub(crate) fn backend_mem_types(
+        &self,
+        backend: NonNull<bindings::nixl_capi_backend_s>,
+    ) -> Result<HashSet<MemType>, NixlError> {
+        Ok(self.backend_caps(backend)?.mem_types.clone())
+    }
+
+    pub(crate) fn backend_supported_ops(
+        &self,
+        backend: NonNull<bindings::nixl_capi_backend_s>,
+        mem_type: MemType,
+    ) -> Result<Vec<XferOp>, NixlError> {
+        if self.backend_caps(backend)?.mem_types.contains(&mem_type) {
+            Ok(vec![XferOp::Read, XferOp::Write])
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    pub(crate) fn backend_max_transfer_size(
+        &self,
+        backend: NonNull<bindings::nixl_capi_backend_s>,
+        mem_type: MemType,
+    ) -> Result<Option<usize>, NixlError> {
+        let caps = self.backend_caps(backend)?;
+        if !caps.mem_types.contains(&mem_type) {
+            return Err(NixlError::InvalidParam);
+        }
+        Ok(caps.max_transfer_size)
+    }
+
+    /// Returns the smallest transfer size limit among backends that register
+    /// `mem_type`, or `None` if none of them was given one
+    fn min_transfer_size_limit(&self, mem_type: MemType) -> Option<usize> {
+        self.backend_caps
+            .values()
+            .filter(|caps| caps.mem_types.contains(&mem_type))
+            .filter_map(|caps| caps.max_transfer_size)
+            .min()
+    }
+
+    /// Polls posted requests until none of them is in progress
+    ///
+    /// Returns true once neither posted nor deferred requests remain in progress.
+    pub(crate) fn poll_posted(&mut self) -> Result<bool, NixlError> {
+        let handle = self.handle.as_ptr();
+        while let Some(&req) = self.posted.first() {
+            // SAFETY: Requests remove themselves from `posted` before they are destroyed
+            match unsafe { bindings::nixl_capi_get_xfer_status(handle, req) } {
+                NIXL_CAPI_IN_PROG => return Ok(false),
+                status => {
+                    self.posted.remove(0);
+                    match status {
+                        NIXL_CAPI_SUCCESS => {}
+                        NIXL_CAPI_ERROR_INVALID_PARAM => return Err(NixlError::InvalidParam),
+                        _ => return Err(NixlError::BackendError),
+                    }
+                }
+            }
+        }
+        self.release_deferred();
+        Ok(self.deferred.is_empty())
+    }
+
+    /// Releases and destroys deferred requests that are no longer in progress
+    pub(crate) fn release_deferred(&mut self) {
+        let handle = self.handle.as_ptr();
+        let mut released = Vec::new();
+        // SAFETY: Deferred requests are owned by the agent until destroyed here
+        self.deferred.retain_mut(|(req, plan)| unsafe {
+            if bindings::nixl_capi_get_xfer_status(handle, *req) == NIXL_CAPI_IN_PROG {
+                return true;
+            }
+            bindings::nixl_capi_release_xfer_req(handle, *req);
+            bindings::nixl_capi_destroy_xfer_req(*req);
+            released.extend(plan.take());
+            false
+        });
+        for plan in released {
+            plan.release(self);
+        }
+    }
+
+    /// Counts posted requests serviced by a backend that are still in progress
+    pub(crate) fn backend_outstanding_ops(
+        &mut self,
+        backend: NonNull<bindings::nixl_capi_backend_s>,
+    ) -> Result<usize, NixlError> {
+        let handle = self.handle.as_ptr();
+        // SAFETY: Requests remove themselves from `posted` before they are destroyed
+        self.posted.retain(|&req| unsafe {
+            bindings::nixl_capi_get_xfer_status(handle, req) == NIXL_CAPI_IN_PROG
+        });
+
+        let mut count = 0;
+        for &req in &self.posted {
+            let mut selected = false;
+            let status = unsafe {
+                bindings::nixl_capi_query_xfer_backend(handle, req, backend.as_ptr(), &mut selected)
+            };
+            match status {
+                NIXL_CAPI_SUCCESS if selected => count += 1,
+                NIXL_CAPI_SUCCESS => {}
+                NIXL_CAPI_ERROR_INVALID_PARAM => return Err(NixlError::InvalidParam),
+                _ => return Err(NixlError::BackendError),
+            }
+        }
+        Ok(count)
+    }
+
+    /// Returns the descriptor count limit declared for a backend, if any
+    pub(crate) fn backend_max_descriptors(
+        &self,
+        backend: NonNull<bindings::nixl_capi_backend_s>,
+    ) -> Result<Option<usize>, NixlError> {
+        Ok(self.backend_caps(backend)?.max_descriptors)
+    }
+
+    /// Returns the queue capacity hint declared for a backend, if any
+    pub(crate) fn backend_queue_capacity(
+        &self,
+        backend: NonNull<bindings::nixl_capi_backend_s>,
+    ) -> Result<Option<usize>, NixlError> {
+        Ok(self.backend_caps(backend)?.queue_capacity)
+    }
+
+    /// Returns the backends NIXL may choose from for a transfer
+    ///
+    /// These are the backends selected in `opt_args`, the default backend when
+    /// no arguments are given, or otherwise every backend of the agent.
+    fn candidate_backends(
+        &self,
+        opt_args: Option<&OptArgs>,
+    ) -> Vec<NonNull<bindings::nixl_capi_backend_s>> {
+        match (opt_args, self.default_backend) {
+            (Some(args), _) if !args.backends.is_empty() => args.backends.clone(),
+            (None, Some(backend)) => vec![backend],
+            _ => self.backends.values().copied().collect(),
+        }
+    }
+
+    /// Validates a transfer against the capabilities of the backends it may use
+    ///
+    /// A backend can carry the transfer if it registers both the local and the
+    /// remote memory type.
+    fn check_xfer(
+        &self,
+        operation: XferOp,
+        local_descs: &XferDescList,
+        remote_descs: &XferDescList,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<(), NixlError> {
+        if self.backends.is_empty() {
+            return Ok(());
+        }
+
+        let local_type = local_descs.get_type()?;
+        let remote_type = remote_descs.get_type()?;
+        let mut supported = false;
+        // The largest limit among capable backends, None if any is unlimited
+        let mut limit = Some(0);
+        for backend in self.candidate_backends(opt_args) {
+            if !self
+                .backend_supported_ops(backend, local_type)?
+                .contains(&operation)
+                || !self
+                    .backend_supported_ops(backend, remote_type)?
+                    .contains(&operation)
+            {
+                continue;
+            }
+            supported = true;
+            limit = match (limit, self.backend_caps(backend)?.max_transfer_size) {
+                (Some(current), Some(backend_limit)) => Some(current.max(backend_limit)),
+                _ => None,
+            };
+        }
+
+        if !supported {
+            return Err(NixlError::OpUnsupported);
+        }
+
+        if let Some(limit) = limit {
+            for index in 0..local_descs.len()? {
+                let (_addr, len, _dev_id) = local_descs.get_desc(index)?;
+                if len > limit {
+                    return Err(NixlError::TransferTooLarge { limit });
+                }
             }
         }
         Ok(())
     }
 
-    fn invalidate_all_remotes(&mut self) -> Result<(), NixlError> {
+    fn load_remote_md(&mut self, metadata: &[u8]) -> Result<String, NixlError> {
+        tracing::trace!(metadata.size = metadata.len(), "Loading remote metadata");
+        let mut agent_name = std::ptr::null_mut();
+
+        let status = unsafe {
+            nixl_capi_load_remote_md(
+                self.handle.as_ptr(),
+                metadata.as_ptr() as *const std::ffi::c_void,
+                metadata.len(),
+                &mut agent_name,
+            )
+        };
+
+        match status {
+            NIXL_CAPI_SUCCESS => {
+                let name = unsafe {
+                    let c_str = std::ffi::CStr::from_ptr(agent_name);
+                    let s = c_str.to_str().unwrap().to_string();
+                    libc::free(agent_name as *mut libc::c_void);
+                    s
+                };
+                self.invalidated.remove(&name);
+                self.remotes.insert(name.clone());
+                tracing::trace!(remote.agent = %name, "Successfully loaded remote metadata");
+                Ok(name)
+            }
+            NIXL_CAPI_ERROR_INVALID_PARAM => {
+                tracing::error!(error = "invalid_param", "Failed to load remote metadata");
+                Err(NixlError::InvalidParam)
+            }
+            _ => {
+                tracing::error!(error = "backend_error", "Failed to load remote metadata");
+                Err(NixlError::BackendError)
+            }
+        }
+    }
+
+    fn refresh_remote_md(&mut self, remote_name: &str, new_md: &[u8]) -> Result<(), NixlError> {
+        self.invalidate_remote_md(remote_name)?;
+        let name = self.load_remote_md(new_md)?;
+        if name != remote_name {
+            tracing::error!(
+                remote.agent = %remote_name,
+                loaded.agent = %name,
+                "Refreshed metadata belongs to a different agent"
+            );
+            return Err(NixlError::InvalidParam);
+        }
+        Ok(())
+    }
+
+    fn invalidate_remote_md(&mut self, remote_agent: &str) -> Result<(), NixlError> {
+        if !self.remotes.remove(remote_agent) {
+            return Err(NixlError::InvalidParam);
+        }
+        self.invalidated.insert(remote_agent.to_string());
+        let remote_agent = CString::new(remote_agent)?;
         unsafe {
-            for remote in self.remotes.drain() {
-                nixl_capi_invalidate_remote_md(self.handle.as_ptr(), remote.as_ptr().cast());
+            nixl_capi_invalidate_remote_md(self.handle.as_ptr(), remote_agent.as_ptr());
+        }
+        Ok(())
+    }
+
+    fn invalidate_all_remotes(&mut self) -> Result<(), NixlError> {
+        for remote in self.remotes.drain() {
+            self.invalidated.insert(remote.clone());
+            let remote = CString::new(remote)?;
+            unsafe {
+                nixl_capi_invalidate_remote_md(self.handle.as_ptr(), remote.as_ptr());
             }
         }
         Ok(())
@@ -866,11 +3080,35 @@ impl AgentInner {
 impl Drop for AgentInner {
     fn drop(&mut self) {
         tracing::trace!("Dropping NIXL agent");
+        let deadline = Instant::now() + DEFERRED_RELEASE_TIMEOUT;
+        let mut backoff = WAIT_BACKOFF_MIN;
+        self.release_deferred();
+        while !self.deferred.is_empty() {
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+            std::thread::sleep(backoff.min(deadline - now));
+            backoff = (backoff * 2).min(WAIT_BACKOFF_MAX);
+            self.release_deferred();
+        }
+        if !self.deferred.is_empty() {
+            tracing::warn!(
+                count = self.deferred.len(),
+                "Destroying agent with transfer requests still in progress"
+            );
+            // The backend may still use the scratch buffers, so they are leaked
+            for (_, plan) in self.deferred.drain(..) {
+                std::mem::forget(plan);
+            }
+        }
         unsafe {
             // invalidate all remotes
             for remote in self.remotes.iter() {
                 tracing::trace!(remote.agent = %remote, "Invalidating remote agent");
-                nixl_capi_invalidate_remote_md(self.handle.as_ptr(), remote.as_ptr().cast());
+                if let Ok(remote) = CString::new(remote.as_str()) {
+                    nixl_capi_invalidate_remote_md(self.handle.as_ptr(), remote.as_ptr());
+                }
             }
 
             // destroy all backends
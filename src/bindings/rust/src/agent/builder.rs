@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use std::net::SocketAddr;
+
+/// Builder for configuring an agent before it is created
+#[derive(Debug, Clone)]
+pub struct AgentBuilder {
+    name: String,
+    track_errors: bool,
+    /// Plugins to create backends for, in order, with parameter overrides
+    backends: Vec<(String, Vec<(String, String)>)>,
+    listen_addr: Option<SocketAddr>,
+}
+
+impl AgentBuilder {
+    /// Creates a builder for an agent with the given name
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            track_errors: false,
+            backends: Vec::new(),
+            listen_addr: None,
+        }
+    }
+
+    /// Keeps a rolling log of errors returned by the agent, see `Agent::recent_errors`
+    pub fn track_errors(mut self, track_errors: bool) -> Self {
+        self.track_errors = track_errors;
+        self
+    }
+
+    /// Starts a listener thread so peers can fetch metadata and connect
+    ///
+    /// With port 0 a free port is picked; see `Agent::listener_addr`.
+    pub fn listen_on(mut self, addr: SocketAddr) -> Self {
+        self.listen_addr = Some(addr);
+        self
+    }
+
+    /// Creates a backend from `plugin` with its default parameters when building
+    pub fn with_backend(mut self, plugin: &str) -> Self {
+        self.backend_entry(plugin);
+        self
+    }
+
+    /// Overrides a default parameter of the backend created from `plugin`
+    ///
+    /// The backend is added if `with_backend` was not called for it.
+    pub fn with_backend_param(mut self, plugin: &str, key: &str, value: &str) -> Self {
+        self.backend_entry(plugin)
+            .push((key.to_string(), value.to_string()));
+        self
+    }
+
+    fn backend_entry(&mut self, plugin: &str) -> &mut Vec<(String, String)> {
+        let index = match self.backends.iter().position(|(name, _)| name == plugin) {
+            Some(index) => index,
+            None => {
+                self.backends.push((plugin.to_string(), Vec::new()));
+                self.backends.len() - 1
+            }
+        };
+        &mut self.backends[index].1
+    }
+
+    /// Creates the agent and its backends
+    ///
+    /// # Errors
+    /// Returns `NixlError::PluginNotFound` if a requested plugin is not
+    /// among `Agent::get_available_plugins`, and `NixlError::Io` if the
+    /// listen address cannot be bound
+    pub fn build(self) -> Result<Agent, NixlError> {
+        let mut agent = match self.listen_addr {
+            Some(addr) => Agent::new_listening(&self.name, addr)?,
+            None => Agent::new(&self.name)?,
+        };
+        if self.track_errors {
+            agent.enable_error_log();
+        }
+
+        if !self.backends.is_empty() {
+            let available = agent.get_available_plugins()?;
+            let available = available.iter().collect::<Result<Vec<_>, _>>()?;
+            if let Some((plugin, _)) = self
+                .backends
+                .iter()
+                .find(|(plugin, _)| !available.contains(&plugin.as_str()))
+            {
+                return Err(NixlError::PluginNotFound(plugin.clone()));
+            }
+        }
+
+        for (plugin, overrides) in &self.backends {
+            let (_mems, mut params) = agent.get_plugin_params(plugin)?;
+            for (key, value) in overrides {
+                params.set(key, value)?;
+            }
+            agent.create_backend(plugin, &params)?;
+        }
+        Ok(agent)
+    }
+}
@@ -0,0 +1,27 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Backend setup of an agent, used to create agents with the same backends
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AgentConfig {
+    pub backends: Vec<BackendConfig>,
+}
+
+/// A backend plugin together with the parameters it was created with
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackendConfig {
+    pub plugin: String,
+    pub params: Vec<(String, String)>,
+}
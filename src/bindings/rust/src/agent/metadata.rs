@@ -0,0 +1,66 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::peer::METADATA_HEADER;
+use super::*;
+
+/// Metadata blob of an agent, exchanged so that peers can load it
+///
+/// With the `serde` feature, it serializes as its raw bytes; deserializing
+/// checks the blob like `from_bytes`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "Vec<u8>", try_from = "Vec<u8>"))]
+pub struct Metadata {
+    bytes: Vec<u8>,
+}
+
+impl Metadata {
+    /// Wraps a blob produced by `Agent::get_local_md`
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if the bytes are not NIXL metadata
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, NixlError> {
+        Self::try_from(bytes.to_vec())
+    }
+
+    /// Returns the raw blob, as accepted by `Agent::load_remote_md`
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl TryFrom<Vec<u8>> for Metadata {
+    type Error = NixlError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, NixlError> {
+        if !bytes.starts_with(METADATA_HEADER) {
+            return Err(NixlError::InvalidParam);
+        }
+        Ok(Self { bytes })
+    }
+}
+
+impl From<Metadata> for Vec<u8> {
+    fn from(metadata: Metadata) -> Self {
+        metadata.bytes
+    }
+}
+
+impl AsRef<[u8]> for Metadata {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
@@ -0,0 +1,139 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Capabilities of a remote agent, as described by its metadata
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemotePeer {
+    pub name: String,
+    /// Memory types of the regions the peer registered
+    pub mem_types: Vec<MemType>,
+    /// Backends the peer can be reached through
+    pub backends: Vec<String>,
+    /// Number of distinct regions the peer registered
+    pub region_count: usize,
+}
+
+impl RemotePeer {
+    /// Parses metadata produced by `Agent::get_local_md`
+    pub(crate) fn parse(metadata: &[u8]) -> Result<Self, NixlError> {
+        let mut reader = MdReader::new(metadata)?;
+
+        let name = reader.string("Agent")?;
+        let mut backends = Vec::new();
+        for _ in 0..reader.usize("Conns")? {
+            backends.push(reader.string("t")?);
+            reader.field("c")?;
+        }
+
+        if reader.field("")? != b"MemSection" {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let mut mem_types = Vec::new();
+        let mut regions = HashSet::new();
+        for _ in 0..reader.usize("nixlSecElms")? {
+            reader.field("bknd")?;
+            if reader.field("nixlDList")? != b"nixlSDList" {
+                return Err(NixlError::InvalidParam);
+            }
+            let mem_type = reader.u32("t")?;
+            reader.field("s")?;
+            for _ in 0..reader.usize("n")? {
+                // Each descriptor starts with its address, length and device ID
+                let desc = reader.field("")?;
+                let basic = desc.get(..24).ok_or(NixlError::InvalidParam)?;
+                regions.insert((mem_type, basic.to_vec()));
+            }
+            let mem_type = MemType::from(mem_type);
+            if !mem_types.contains(&mem_type) {
+                mem_types.push(mem_type);
+            }
+        }
+
+        Ok(Self {
+            name,
+            mem_types,
+            backends,
+            region_count: regions.len(),
+        })
+    }
+}
+
+/// Prefix of every serialized metadata blob
+pub(super) const METADATA_HEADER: &[u8] = b"nixlSerDes|";
+
+/// Reads the tagged, length-prefixed fields of serialized metadata
+struct MdReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> MdReader<'a> {
+    fn new(data: &'a [u8]) -> Result<Self, NixlError> {
+        if !data.starts_with(METADATA_HEADER) {
+            return Err(NixlError::InvalidParam);
+        }
+        Ok(Self {
+            data,
+            offset: METADATA_HEADER.len(),
+        })
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], NixlError> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .ok_or(NixlError::InvalidParam)?;
+        let bytes = self
+            .data
+            .get(self.offset..end)
+            .ok_or(NixlError::InvalidParam)?;
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn field(&mut self, tag: &str) -> Result<&'a [u8], NixlError> {
+        if self.take(tag.len())? != tag.as_bytes() {
+            return Err(NixlError::InvalidParam);
+        }
+        let len = usize::from_ne_bytes(self.take(size_of::<usize>())?.try_into().unwrap());
+        let value = self.take(len)?;
+        if self.take(1)? != b"|" {
+            return Err(NixlError::InvalidParam);
+        }
+        Ok(value)
+    }
+
+    fn string(&mut self, tag: &str) -> Result<String, NixlError> {
+        let value = self.field(tag)?;
+        String::from_utf8(value.to_vec()).map_err(|_| NixlError::InvalidParam)
+    }
+
+    fn usize(&mut self, tag: &str) -> Result<usize, NixlError> {
+        let value = self.field(tag)?.try_into();
+        value
+            .map(usize::from_ne_bytes)
+            .map_err(|_| NixlError::InvalidParam)
+    }
+
+    fn u32(&mut self, tag: &str) -> Result<u32, NixlError> {
+        let value = self.field(tag)?.try_into();
+        value
+            .map(u32::from_ne_bytes)
+            .map_err(|_| NixlError::InvalidParam)
+    }
+}
@@ -0,0 +1,642 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use std::ffi::c_void;
+use std::os::fd::AsRawFd;
+use std::sync::Mutex;
+
+const CUDA_SUCCESS: i32 = 0;
+const CUDA_MEMCPY_HOST_TO_DEVICE: i32 = 1;
+const CUDA_MEMCPY_DEVICE_TO_HOST: i32 = 2;
+
+extern "C" {
+    fn cudaSetDevice(device: i32) -> i32;
+    fn cudaMalloc(ptr: *mut *mut c_void, size: usize) -> i32;
+    fn cudaFree(ptr: *mut c_void) -> i32;
+    fn cudaMemset(ptr: *mut c_void, value: i32, count: usize) -> i32;
+    fn cudaMemcpy(dst: *mut c_void, src: *const c_void, count: usize, kind: i32) -> i32;
+    fn cuCtxGetCurrent(ctx: *mut *mut c_void) -> i32;
+}
+
+fn check(status: i32) -> Result<(), NixlError> {
+    match status {
+        CUDA_SUCCESS => Ok(()),
+        status => Err(NixlError::CudaError(status)),
+    }
+}
+
+/// The CUDA context current on this thread, or null if there is none
+fn current_context() -> Result<usize, NixlError> {
+    let mut ctx = ptr::null_mut();
+    // SAFETY: ctx is a valid location for the context handle
+    check(unsafe { cuCtxGetCurrent(&mut ctx) })?;
+    Ok(ctx as usize)
+}
+
+/// GPU device memory storage allocated with `cudaMalloc`
+///
+/// Registering records the CUDA context current on the calling thread, and
+/// the memory is only deregistered while that context is current again.
+#[derive(Debug)]
+pub struct CudaStorage {
+    ptr: NonNull<u8>,
+    size: usize,
+    device_id: u32,
+    handle: Option<RegistrationHandle>,
+    context: usize,
+}
+
+// SAFETY: The allocation is owned by this storage and only accessed through it
+unsafe impl Send for CudaStorage {}
+// SAFETY: Shared access only reads the allocation
+unsafe impl Sync for CudaStorage {}
+
+impl CudaStorage {
+    /// Allocates `size` bytes on a CUDA device
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if `size` is zero, and
+    /// `NixlError::CudaError` if the device cannot be selected or allocated on
+    pub fn new(size: usize, device_id: u32) -> Result<Self, NixlError> {
+        if size == 0 {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let mut ptr = ptr::null_mut();
+        // SAFETY: ptr is a valid location for the allocation address
+        unsafe {
+            check(cudaSetDevice(device_id as i32))?;
+            check(cudaMalloc(&mut ptr, size))?;
+        }
+
+        Ok(Self {
+            ptr: NonNull::new(ptr.cast()).ok_or(NixlError::InvalidDataPointer)?,
+            size,
+            device_id,
+            handle: None,
+            context: 0,
+        })
+    }
+
+    /// Deregisters the storage, keeping the allocation usable
+    ///
+    /// Does nothing if the storage is not registered.
+    ///
+    /// # Errors
+    /// Returns `NixlError::WrongCudaContext` if the context current on this
+    /// thread is not the one the storage was registered in; the storage stays
+    /// registered
+    pub fn deregister(&mut self) -> Result<(), NixlError> {
+        if self.handle.is_none() {
+            return Ok(());
+        }
+        if current_context()? != self.context {
+            return Err(NixlError::WrongCudaContext);
+        }
+        match self.handle.take() {
+            Some(mut handle) => handle.deregister(),
+            None => Ok(()),
+        }
+    }
+
+    /// Fill the storage with a specific byte value
+    pub fn memset(&mut self, value: u8) -> Result<(), NixlError> {
+        // SAFETY: The allocation is valid for `size` bytes
+        unsafe {
+            check(cudaSetDevice(self.device_id as i32))?;
+            check(cudaMemset(
+                self.ptr.as_ptr().cast(),
+                value as i32,
+                self.size,
+            ))
+        }
+    }
+
+    /// Copies host data to the start of the storage
+    ///
+    /// Returns `NixlError::InvalidParam` if `data` is larger than the storage.
+    pub fn copy_from_host(&mut self, data: &[u8]) -> Result<(), NixlError> {
+        if data.len() > self.size {
+            return Err(NixlError::InvalidParam);
+        }
+        // SAFETY: Both buffers are valid for data.len() bytes
+        unsafe {
+            check(cudaSetDevice(self.device_id as i32))?;
+            check(cudaMemcpy(
+                self.ptr.as_ptr().cast(),
+                data.as_ptr().cast(),
+                data.len(),
+                CUDA_MEMCPY_HOST_TO_DEVICE,
+            ))
+        }
+    }
+
+    /// Copies the start of the storage to a host buffer
+    ///
+    /// Returns `NixlError::InvalidParam` if `out` is larger than the storage.
+    pub fn copy_to_host(&self, out: &mut [u8]) -> Result<(), NixlError> {
+        if out.len() > self.size {
+            return Err(NixlError::InvalidParam);
+        }
+        // SAFETY: Both buffers are valid for out.len() bytes
+        unsafe {
+            check(cudaSetDevice(self.device_id as i32))?;
+            check(cudaMemcpy(
+                out.as_mut_ptr().cast(),
+                self.ptr.as_ptr().cast(),
+                out.len(),
+                CUDA_MEMCPY_DEVICE_TO_HOST,
+            ))
+        }
+    }
+
+    /// Registers the memory covered by a strided tensor on a CUDA device
+    ///
+    /// The registered range spans from `base_ptr` to the end of the last
+    /// element. The layout is kept so that descriptors for sub-views, such as
+    /// a single row, can be added with `TensorRegistration::add_view_descs`.
+    ///
+    /// # Arguments
+    /// * `agent` - The agent to register with
+    /// * `base_ptr` - Device address of the first element
+    /// * `shape` - Number of elements in each dimension, outermost first
+    /// * `strides` - Distance between neighbouring elements of each dimension, in elements
+    /// * `elem_size` - Size of one element in bytes
+    /// * `device_id` - The CUDA device holding the tensor
+    /// * `opt_args` - Optional arguments for the registration
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if the shape is empty, has a zero
+    /// dimension, or does not match the strides, or if `elem_size` is zero
+    ///
+    /// # Safety
+    /// The whole covered range must be device memory on `device_id` that
+    /// stays allocated until the registration is dropped.
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn register_tensor(
+        agent: &Agent,
+        base_ptr: *const u8,
+        shape: &[usize],
+        strides: &[usize],
+        elem_size: usize,
+        device_id: u32,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<TensorRegistration, NixlError> {
+        if shape.is_empty() || shape.len() != strides.len() || shape.contains(&0) || elem_size == 0
+        {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let last: usize = shape
+            .iter()
+            .zip(strides)
+            .map(|(dim, stride)| (dim - 1) * stride)
+            .sum();
+        let mut tensor = TensorRegistration {
+            base: base_ptr as usize,
+            size: (last + 1) * elem_size,
+            device_id,
+            shape: shape.to_vec(),
+            strides: strides.to_vec(),
+            elem_size,
+            handle: None,
+        };
+        tensor.handle = Some(agent.register_memory(&tensor, opt_args)?);
+        Ok(tensor)
+    }
+}
+
+impl Drop for CudaStorage {
+    fn drop(&mut self) {
+        // Deregister before the memory goes away
+        match self.deregister() {
+            Ok(()) => {}
+            Err(NixlError::WrongCudaContext) => {
+                // Releasing the memory outside its context is what the check
+                // guards against, so the registration and allocation are leaked
+                tracing::error!("CUDA storage dropped outside its context, leaking it");
+                if let Some(handle) = self.handle.take() {
+                    handle.leak();
+                }
+                return;
+            }
+            Err(e) => {
+                tracing::error!(error = ?e, "Failed to deregister CUDA storage");
+                self.handle.take();
+            }
+        }
+        // SAFETY: ptr was allocated with cudaMalloc on device_id in `new`
+        unsafe {
+            cudaSetDevice(self.device_id as i32);
+            cudaFree(self.ptr.as_ptr().cast());
+        }
+    }
+}
+
+impl MemoryRegion for CudaStorage {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    unsafe fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+}
+
+impl NixlDescriptor for CudaStorage {
+    fn mem_type(&self) -> MemType {
+        MemType::Vram
+    }
+
+    fn device_id(&self) -> u64 {
+        self.device_id as u64
+    }
+}
+
+impl NixlRegistration for CudaStorage {
+    fn register(&mut self, agent: &Agent, opt_args: Option<&OptArgs>) -> Result<(), NixlError> {
+        let context = current_context()?;
+        let handle = agent.register_memory(self, opt_args)?;
+        self.handle = Some(handle);
+        self.context = context;
+        Ok(())
+    }
+}
+
+/// A fixed set of registered device buffers of one size, handed out one at a time
+///
+/// Every buffer is allocated and registered when the pool is created, so
+/// checking one out costs neither a `cudaMalloc` nor a registration. A
+/// checked out buffer returns to the pool when its guard is dropped.
+#[derive(Debug)]
+pub struct CudaStoragePool {
+    free: Arc<Mutex<Vec<CudaStorage>>>,
+    buffer_size: usize,
+    count: usize,
+}
+
+impl CudaStoragePool {
+    /// Allocates `count` buffers of `buffer_size` bytes on a CUDA device and
+    /// registers them with `agent`
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if `buffer_size` or `count` is zero,
+    /// `NixlError::CudaError` if a buffer cannot be allocated, and the
+    /// registration error if one cannot be registered
+    pub fn new(
+        agent: &Agent,
+        buffer_size: usize,
+        count: usize,
+        device_id: u32,
+    ) -> Result<Self, NixlError> {
+        if count == 0 {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let mut free = Vec::with_capacity(count);
+        for _ in 0..count {
+            let mut storage = CudaStorage::new(buffer_size, device_id)?;
+            storage.register(agent, None)?;
+            free.push(storage);
+        }
+
+        Ok(Self {
+            free: Arc::new(Mutex::new(free)),
+            buffer_size,
+            count,
+        })
+    }
+
+    /// Takes a buffer from the pool, or returns `None` if all are checked out
+    pub fn checkout(&self) -> Option<PooledCudaStorage> {
+        let storage = self.free.lock().unwrap().pop()?;
+        Some(PooledCudaStorage {
+            storage: Some(storage),
+            free: self.free.clone(),
+        })
+    }
+
+    /// Number of buffers currently in the pool
+    pub fn available(&self) -> usize {
+        self.free.lock().unwrap().len()
+    }
+
+    /// Number of buffers the pool was created with
+    pub fn capacity(&self) -> usize {
+        self.count
+    }
+
+    /// Size of each buffer in bytes
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+}
+
+/// A buffer checked out of a `CudaStoragePool`
+///
+/// Dereferences to the registered `CudaStorage` and returns it to the pool
+/// on drop. A buffer returned after the pool is dropped is freed once every
+/// other checked out buffer is too.
+#[derive(Debug)]
+pub struct PooledCudaStorage {
+    storage: Option<CudaStorage>,
+    free: Arc<Mutex<Vec<CudaStorage>>>,
+}
+
+impl std::ops::Deref for PooledCudaStorage {
+    type Target = CudaStorage;
+
+    fn deref(&self) -> &CudaStorage {
+        self.storage.as_ref().unwrap()
+    }
+}
+
+impl std::ops::DerefMut for PooledCudaStorage {
+    fn deref_mut(&mut self) -> &mut CudaStorage {
+        self.storage.as_mut().unwrap()
+    }
+}
+
+impl Drop for PooledCudaStorage {
+    fn drop(&mut self) {
+        if let Some(storage) = self.storage.take() {
+            self.free.lock().unwrap().push(storage);
+        }
+    }
+}
+
+/// Device memory paired with a file for direct transfers through the GDS backend
+///
+/// The storage itself describes the device buffer, so it can be added to
+/// `MemType::Vram` descriptor lists like `CudaStorage`; `add_file_desc` adds
+/// the matching file segment, keyed by its file descriptor, to a
+/// `MemType::File` list. Registering registers both the buffer and the file.
+#[derive(Debug)]
+pub struct GdsStorage {
+    buffer: CudaStorage,
+    file: std::fs::File,
+    file_handle: Option<RegistrationHandle>,
+}
+
+/// The file segment of a `GdsStorage`, registered under `MemType::File`
+#[derive(Debug)]
+struct GdsFile {
+    fd: i32,
+    size: usize,
+}
+
+impl GdsStorage {
+    /// Opens or creates the file at `path` and allocates a matching device buffer
+    ///
+    /// The file is extended to `size` bytes if it is shorter.
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if `size` is zero, `NixlError::Io` if
+    /// the file cannot be opened or resized, and `NixlError::CudaError` if the
+    /// device buffer cannot be allocated
+    pub fn open(path: &std::path::Path, size: usize, device_id: u32) -> Result<Self, NixlError> {
+        if size == 0 {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        if file.metadata()?.len() < size as u64 {
+            file.set_len(size as u64)?;
+        }
+
+        Ok(Self {
+            buffer: CudaStorage::new(size, device_id)?,
+            file,
+            file_handle: None,
+        })
+    }
+
+    /// The device buffer
+    pub fn buffer(&self) -> &CudaStorage {
+        &self.buffer
+    }
+
+    /// The device buffer, mutably
+    pub fn buffer_mut(&mut self) -> &mut CudaStorage {
+        &mut self.buffer
+    }
+
+    /// The file descriptor used as the device ID of the file segment
+    pub fn fd(&self) -> i32 {
+        self.file.as_raw_fd()
+    }
+
+    /// Adds a descriptor covering the file segment to a `MemType::File` list and
+    /// returns its index
+    pub fn add_file_desc(&self, dlist: &mut XferDescList) -> Result<usize, NixlError> {
+        dlist.add_desc(0, self.buffer.size(), self.fd() as u64)
+    }
+
+    fn file_segment(&self) -> GdsFile {
+        GdsFile {
+            fd: self.fd(),
+            size: self.buffer.size(),
+        }
+    }
+}
+
+impl Drop for GdsStorage {
+    fn drop(&mut self) {
+        // Deregister the file before its descriptor is closed
+        self.file_handle.take();
+    }
+}
+
+impl MemoryRegion for GdsStorage {
+    fn size(&self) -> usize {
+        self.buffer.size()
+    }
+
+    unsafe fn as_ptr(&self) -> *const u8 {
+        self.buffer.as_ptr()
+    }
+}
+
+impl NixlDescriptor for GdsStorage {
+    fn mem_type(&self) -> MemType {
+        MemType::Vram
+    }
+
+    fn device_id(&self) -> u64 {
+        self.buffer.device_id()
+    }
+}
+
+impl NixlRegistration for GdsStorage {
+    /// Registers the device buffer and the file
+    ///
+    /// # Errors
+    /// Returns `NixlError::GdsError` if the GDS backend rejects the file
+    fn register(&mut self, agent: &Agent, opt_args: Option<&OptArgs>) -> Result<(), NixlError> {
+        self.buffer.register(agent, opt_args)?;
+        let mut handles = agent
+            .register_memory_as(&self.file_segment(), &[MemType::File], opt_args)
+            .map_err(|e| match e {
+                NixlError::BackendError => {
+                    NixlError::GdsError(format!("failed to register file descriptor {}", self.fd()))
+                }
+                e => e,
+            })?;
+        self.file_handle = handles.pop();
+        Ok(())
+    }
+}
+
+impl MemoryRegion for GdsFile {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    unsafe fn as_ptr(&self) -> *const u8 {
+        ptr::null()
+    }
+}
+
+impl NixlDescriptor for GdsFile {
+    fn mem_type(&self) -> MemType {
+        MemType::File
+    }
+
+    fn device_id(&self) -> u64 {
+        self.fd as u64
+    }
+}
+
+/// A registered strided tensor on a CUDA device
+#[derive(Debug)]
+pub struct TensorRegistration {
+    base: usize,
+    size: usize,
+    device_id: u32,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    elem_size: usize,
+    handle: Option<RegistrationHandle>,
+}
+
+impl TensorRegistration {
+    /// Number of elements in each dimension
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Distance between neighbouring elements of each dimension, in elements
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    /// Size of one element in bytes
+    pub fn elem_size(&self) -> usize {
+        self.elem_size
+    }
+
+    /// Adds descriptors covering the sub-view selected by fixing the leading indices
+    ///
+    /// With the tensor's full rank of indices this selects one element; with
+    /// one index fewer, one innermost row, and so on. Each contiguous run of
+    /// elements becomes one descriptor, in row-major order.
+    ///
+    /// # Returns
+    /// The number of descriptors added
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if there are more indices than
+    /// dimensions, and `NixlError::IndexOutOfBounds` if an index is out of range
+    pub fn add_view_descs(
+        &self,
+        dlist: &mut XferDescList,
+        index: &[usize],
+    ) -> Result<usize, NixlError> {
+        if index.len() > self.shape.len() {
+            return Err(NixlError::InvalidParam);
+        }
+        if index.iter().zip(&self.shape).any(|(i, dim)| i >= dim) {
+            return Err(NixlError::IndexOutOfBounds);
+        }
+
+        let offset: usize = index.iter().zip(&self.strides).map(|(i, s)| i * s).sum();
+        let rank = self.shape.len();
+        // The innermost dimension forms one run when its elements are adjacent
+        let (run_len, outer_dims) = if index.len() < rank && self.strides[rank - 1] == 1 {
+            (self.shape[rank - 1], rank - 1)
+        } else {
+            (1, rank)
+        };
+
+        let free = &self.shape[index.len()..outer_dims];
+        let free_strides = &self.strides[index.len()..outer_dims];
+        let mut position = vec![0; free.len()];
+        let mut added = 0;
+        loop {
+            let start = offset
+                + position
+                    .iter()
+                    .zip(free_strides)
+                    .map(|(i, s)| i * s)
+                    .sum::<usize>();
+            dlist.add_desc(
+                self.base + start * self.elem_size,
+                run_len * self.elem_size,
+                self.device_id as u64,
+            )?;
+            added += 1;
+
+            // Advance the free indices like an odometer, innermost first
+            let mut dim = free.len();
+            loop {
+                if dim == 0 {
+                    return Ok(added);
+                }
+                dim -= 1;
+                position[dim] += 1;
+                if position[dim] < free[dim] {
+                    break;
+                }
+                position[dim] = 0;
+            }
+        }
+    }
+}
+
+impl MemoryRegion for TensorRegistration {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    unsafe fn as_ptr(&self) -> *const u8 {
+        self.base as *const u8
+    }
+}
+
+impl NixlDescriptor for TensorRegistration {
+    fn mem_type(&self) -> MemType {
+        MemType::Vram
+    }
+
+    fn device_id(&self) -> u64 {
+        self.device_id as u64
+    }
+}
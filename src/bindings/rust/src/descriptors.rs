@@ -15,16 +15,22 @@
 
 use super::*;
 
+mod builder;
 mod query;
 mod reg;
+mod template;
 mod xfer;
 
-pub use query::{QueryResponse, QueryResponseIterator, QueryResponseList};
-pub use reg::RegDescList;
-pub use xfer::XferDescList;
+pub use builder::ConcurrentDescListBuilder;
+pub use query::{
+    PosixFileInfo, QueryResponse, QueryResponseIterator, QueryResponseList, QueryResult,
+};
+pub use reg::{RegDescIter, RegDescList};
+pub use template::XferDescListTemplate;
+pub use xfer::{XferDescIter, XferDescList};
 
 /// Memory types supported by NIXL
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MemType {
     Dram,
     Vram,
@@ -66,3 +72,13 @@ impl fmt::Display for MemType {
         }
     }
 }
+
+/// A descriptor read back from a descriptor list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Desc {
+    pub addr: usize,
+    pub len: usize,
+    pub dev_id: u64,
+    /// Metadata attached to a registration descriptor, `None` for transfer descriptors
+    pub meta: Option<Vec<u8>>,
+}
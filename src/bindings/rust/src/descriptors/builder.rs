@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Mutex;
+
+/// Collects transfer descriptors from multiple threads into a single list
+///
+/// Descriptors are pushed through an internal channel, so `push` can be called
+/// concurrently through a shared reference. The NIXL list itself is only built
+/// on `finish`, from a single thread.
+pub struct ConcurrentDescListBuilder {
+    mem_type: MemType,
+    sorted: bool,
+    sender: Sender<(usize, usize, u64)>,
+    receiver: Mutex<Receiver<(usize, usize, u64)>>,
+}
+
+impl ConcurrentDescListBuilder {
+    /// Creates a new builder for a transfer descriptor list of the given memory type
+    pub fn new(mem_type: MemType, sorted: bool) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            mem_type,
+            sorted,
+            sender,
+            receiver: Mutex::new(receiver),
+        }
+    }
+
+    /// Queues a descriptor for the final list
+    pub fn push(&self, addr: usize, len: usize, dev_id: u64) {
+        // The receiver lives as long as self, so sending cannot fail
+        let _ = self.sender.send((addr, len, dev_id));
+    }
+
+    /// Builds the transfer descriptor list from every queued descriptor
+    pub fn finish<'a>(self) -> Result<XferDescList<'a>, NixlError> {
+        let Self {
+            mem_type,
+            sorted,
+            sender,
+            receiver,
+        } = self;
+        drop(sender);
+
+        let receiver = receiver.into_inner().map_err(|_| NixlError::BackendError)?;
+        let mut dlist = XferDescList::new(mem_type, sorted)?;
+        for (addr, len, dev_id) in receiver.iter() {
+            dlist.add_desc(addr, len, dev_id)?;
+        }
+        Ok(dlist)
+    }
+}
@@ -15,12 +15,39 @@
 
 use super::*;
 use crate::Params;
+use std::time::SystemTime;
 
 /// A safe wrapper around a NIXL query response list
 pub struct QueryResponseList {
     inner: NonNull<bindings::nixl_capi_query_resp_list_s>,
 }
 
+/// A query response with the parameters NIXL backends report parsed into typed fields
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryResult {
+    /// Whether the queried resource exists
+    pub exists: bool,
+    /// File size in bytes, as reported by file backends such as POSIX
+    pub size: Option<u64>,
+    /// Last modification time, as reported by file backends such as POSIX
+    pub mtime: Option<SystemTime>,
+    /// File type and permission bits, as reported by file backends such as POSIX
+    pub mode: Option<u32>,
+    /// Parameters that are not recognized or could not be parsed
+    pub raw: HashMap<String, String>,
+}
+
+/// File metadata reported by the POSIX backend for a queried file
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PosixFileInfo {
+    /// Last modification time
+    pub modified: SystemTime,
+    /// File type and permission bits, as in `st_mode`
+    pub mode: u32,
+    /// File size in bytes, if the backend reported it
+    pub size: Option<u64>,
+}
+
 /// Represents a single query response which may or may not contain parameters
 pub struct QueryResponse<'a> {
     list: &'a QueryResponseList,
@@ -80,6 +107,11 @@ impl QueryResponseList {
         })
     }
 
+    /// Converts every response into a typed `QueryResult`, in descriptor order
+    pub fn into_vec(self) -> Result<Vec<QueryResult>, NixlError> {
+        self.iter()?.map(|response| response.to_result()).collect()
+    }
+
     pub(crate) fn handle(&self) -> *mut bindings::nixl_capi_query_resp_list_s {
         self.inner.as_ptr()
     }
@@ -125,6 +157,65 @@ impl<'a> QueryResponse<'a> {
             _ => Err(NixlError::BackendError),
         }
     }
+
+    /// Parses the POSIX file metadata in this response
+    ///
+    /// Returns `None` if the file does not exist or the response lacks a
+    /// valid `mtime` or `mode`, as it does for non-POSIX backends.
+    pub fn as_posix_file_info(&self) -> Option<PosixFileInfo> {
+        let result = self.to_result().ok()?;
+        Some(PosixFileInfo {
+            modified: result.mtime?,
+            mode: result.mode?,
+            size: result.size,
+        })
+    }
+
+    /// Parses this response into a typed `QueryResult`
+    pub fn to_result(&self) -> Result<QueryResult, NixlError> {
+        let mut result = QueryResult {
+            exists: false,
+            size: None,
+            mtime: None,
+            mode: None,
+            raw: HashMap::new(),
+        };
+
+        let params = match self.get_params()? {
+            Some(params) => params,
+            None => return Ok(result),
+        };
+        result.exists = true;
+
+        for param in params.iter()? {
+            let param = param?;
+            let parsed = match param.key {
+                "size" => {
+                    result.size = param.value.parse().ok();
+                    result.size.is_some()
+                }
+                "mode" => {
+                    result.mode = param.value.parse().ok();
+                    result.mode.is_some()
+                }
+                "mtime" => {
+                    result.mtime = param
+                        .value
+                        .parse()
+                        .ok()
+                        .map(|secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+                    result.mtime.is_some()
+                }
+                _ => false,
+            };
+            if !parsed {
+                result
+                    .raw
+                    .insert(param.key.to_string(), param.value.to_string());
+            }
+        }
+        Ok(result)
+    }
 }
 
 /// An iterator over query responses
@@ -14,6 +14,7 @@
 // limitations under the License.
 
 use super::*;
+use std::path::Path;
 
 /// A safe wrapper around a NIXL registration descriptor list
 pub struct RegDescList<'a> {
@@ -23,6 +24,9 @@ pub struct RegDescList<'a> {
 
 impl<'a> RegDescList<'a> {
     /// Creates a new registration descriptor list for the given memory type
+    ///
+    /// A sorted list keeps its descriptors ordered by device and address as they
+    /// are added. Use `is_sorted` to read the flag back.
     pub fn new(mem_type: MemType, sorted: bool) -> Result<Self, NixlError> {
         let mut dlist = ptr::null_mut();
         let status = unsafe {
@@ -61,19 +65,20 @@ impl<'a> RegDescList<'a> {
         }
     }
 
-    /// Adds a descriptor to the list
-    pub fn add_desc(&mut self, addr: usize, len: usize, dev_id: u64) -> Result<(), NixlError> {
+    /// Adds a descriptor to the list and returns its index
+    pub fn add_desc(&mut self, addr: usize, len: usize, dev_id: u64) -> Result<usize, NixlError> {
         self.add_desc_with_meta(addr, len, dev_id, &[])
     }
 
-    /// Add a descriptor with metadata
+    /// Add a descriptor with metadata and returns its index
     pub fn add_desc_with_meta(
         &mut self,
         addr: usize,
         len: usize,
         dev_id: u64,
         metadata: &[u8],
-    ) -> Result<(), NixlError> {
+    ) -> Result<usize, NixlError> {
+        let mut index = 0;
         let status = unsafe {
             nixl_capi_reg_dlist_add_desc(
                 self.inner.as_ptr(),
@@ -82,16 +87,109 @@ impl<'a> RegDescList<'a> {
                 dev_id,
                 metadata.as_ptr() as *const std::ffi::c_void,
                 metadata.len(),
+                &mut index,
             )
         };
 
         match status {
-            NIXL_CAPI_SUCCESS => Ok(()),
+            NIXL_CAPI_SUCCESS => Ok(index),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Adds a file descriptor whose metadata is the file's path and returns its index
+    ///
+    /// # Errors
+    /// Returns `NixlError::StringConversionError` if the path contains a NUL
+    /// byte, since the POSIX backend would only see the part before it
+    pub fn add_file_desc(
+        &mut self,
+        offset: usize,
+        len: usize,
+        dev_id: u64,
+        path: &Path,
+    ) -> Result<usize, NixlError> {
+        let path = CString::new(path.to_string_lossy().as_bytes())?;
+        self.add_desc_with_meta(offset, len, dev_id, path.as_bytes())
+    }
+
+    /// Adds a file descriptor for each regular file in `dir` and returns how many were added
+    ///
+    /// Each descriptor covers the whole file, with its path as metadata as in
+    /// `add_file_desc`. Files are added in path order. With `recursive`, files
+    /// in subdirectories are added too; symbolic links are not followed.
+    pub fn add_directory(&mut self, dir: &Path, recursive: bool) -> Result<usize, NixlError> {
+        let mut entries = std::fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.sort();
+
+        let mut added = 0;
+        for path in entries {
+            let metadata = std::fs::symlink_metadata(&path)?;
+            if metadata.is_file() {
+                self.add_file_desc(0, metadata.len() as usize, 0, &path)?;
+                added += 1;
+            } else if metadata.is_dir() && recursive {
+                added += self.add_directory(&path, true)?;
+            }
+        }
+        Ok(added)
+    }
+
+    /// Returns the descriptor at `index`, including its metadata
+    pub fn get(&self, index: usize) -> Result<Desc, NixlError> {
+        if index >= self.len()? {
+            return Err(NixlError::IndexOutOfBounds);
+        }
+
+        let mut addr: uintptr_t = 0;
+        let mut len = 0;
+        let mut dev_id = 0;
+        let mut meta = ptr::null();
+        let mut meta_len = 0;
+        let status = unsafe {
+            nixl_capi_reg_dlist_get_desc(
+                self.inner.as_ptr(),
+                index,
+                &mut addr,
+                &mut len,
+                &mut dev_id,
+                &mut meta,
+                &mut meta_len,
+            )
+        };
+
+        match status {
+            NIXL_CAPI_SUCCESS => {
+                // SAFETY: The metadata is valid until the list is modified, and is copied here
+                let meta = if meta_len == 0 {
+                    Vec::new()
+                } else {
+                    unsafe { std::slice::from_raw_parts(meta.cast::<u8>(), meta_len) }.to_vec()
+                };
+                Ok(Desc {
+                    addr: addr as usize,
+                    len,
+                    dev_id,
+                    meta: Some(meta),
+                })
+            }
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
             _ => Err(NixlError::BackendError),
         }
     }
 
+    /// Returns an iterator over the descriptors in the list
+    pub fn iter(&self) -> RegDescIter<'_, 'a> {
+        RegDescIter {
+            list: self,
+            index: 0,
+            done: false,
+        }
+    }
+
     /// Returns true if the list is empty
     pub fn is_empty(&self) -> Result<bool, NixlError> {
         Ok(self.len()? == 0)
@@ -135,6 +233,10 @@ impl<'a> RegDescList<'a> {
         Self::verify_sorted_inner(self.inner)
     }
 
+    /// Returns true if the list was created sorted or has been sorted since
+    ///
+    /// This reads the flag without inspecting the descriptors. Use
+    /// `verify_sorted` to check the actual order.
     pub fn is_sorted(&self) -> Result<bool, NixlError> {
         let mut is_sorted = false;
         let status = unsafe { nixl_capi_reg_dlist_is_sorted(self.inner.as_ptr(), &mut is_sorted) };
@@ -146,6 +248,9 @@ impl<'a> RegDescList<'a> {
     }
 
     /// Returns true if any descriptors in the list overlap
+    ///
+    /// Sorted lists only compare neighbouring descriptors, which is linear in
+    /// the list length. Unsorted lists compare every pair.
     pub fn has_overlaps(&self) -> Result<bool, NixlError> {
         let mut has_overlaps = false;
         let status =
@@ -158,6 +263,19 @@ impl<'a> RegDescList<'a> {
         }
     }
 
+    /// Sorts the descriptors by device and address and marks the list as sorted
+    ///
+    /// Metadata stays attached to its descriptor.
+    pub fn sort_by_addr(&mut self) -> Result<(), NixlError> {
+        let status = unsafe { nixl_capi_reg_dlist_sort(self.inner.as_ptr()) };
+
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(()),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
     /// Trims the list to the given size
     pub fn trim(&mut self) -> Result<(), NixlError> {
         let status = unsafe { nixl_capi_reg_dlist_trim(self.inner.as_ptr()) };
@@ -213,13 +331,13 @@ impl<'a> RegDescList<'a> {
         }
     }
 
-    /// Add a descriptor from a type implementing NixlDescriptor
+    /// Add a descriptor from a type implementing NixlDescriptor and returns its index
     ///
     /// # Safety
     /// The caller must ensure that:
     /// - The descriptor remains valid for the lifetime of the list
     /// - The memory region pointed to by the descriptor remains valid
-    pub fn add_storage_desc(&mut self, desc: &'a dyn NixlDescriptor) -> Result<(), NixlError> {
+    pub fn add_storage_desc(&mut self, desc: &'a dyn NixlDescriptor) -> Result<usize, NixlError> {
         // Validate memory type matches
         let desc_mem_type = desc.mem_type();
         let list_mem_type = unsafe {
@@ -256,6 +374,22 @@ impl<'a> RegDescList<'a> {
     }
 }
 
+impl fmt::Debug for RegDescList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_struct("RegDescList");
+        if let Ok(mem_type) = self.get_type() {
+            list.field("mem_type", &mem_type);
+        }
+        if let Ok(len) = self.len() {
+            list.field("len", &len);
+        }
+        if let Ok(sorted) = self.is_sorted() {
+            list.field("sorted", &sorted);
+        }
+        list.finish()
+    }
+}
+
 impl Drop for RegDescList<'_> {
     fn drop(&mut self) {
         tracing::trace!("Dropping registration descriptor list");
@@ -265,3 +399,42 @@ impl Drop for RegDescList<'_> {
         tracing::trace!("Registration descriptor list dropped");
     }
 }
+
+/// An iterator over the descriptors of a registration descriptor list
+pub struct RegDescIter<'b, 'a> {
+    list: &'b RegDescList<'a>,
+    index: usize,
+    done: bool,
+}
+
+impl Iterator for RegDescIter<'_, '_> {
+    type Item = Result<Desc, NixlError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let desc = match self.list.len() {
+            Ok(len) if self.index < len => self.list.get(self.index),
+            Ok(_) => {
+                self.done = true;
+                return None;
+            }
+            Err(e) => {
+                self.done = true;
+                Err(e)
+            }
+        };
+        self.index += 1;
+        Some(desc)
+    }
+}
+
+impl<'b, 'a> IntoIterator for &'b RegDescList<'a> {
+    type Item = Result<Desc, NixlError>;
+    type IntoIter = RegDescIter<'b, 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,70 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A fixed transfer shape whose descriptors are relative to a base address
+///
+/// Build the template once, then call `instantiate` with each request's base
+/// address to get a concrete transfer descriptor list.
+#[derive(Debug, Clone)]
+pub struct XferDescListTemplate {
+    mem_type: MemType,
+    sorted: bool,
+    descs: Vec<(u64, usize, u64)>,
+}
+
+impl XferDescListTemplate {
+    /// Creates an empty template for transfer descriptor lists of the given memory type
+    pub fn new(mem_type: MemType, sorted: bool) -> Self {
+        Self {
+            mem_type,
+            sorted,
+            descs: Vec::new(),
+        }
+    }
+
+    /// Adds a descriptor starting `offset` bytes past the base address
+    pub fn add_desc(&mut self, offset: u64, len: usize, dev_id: u64) {
+        self.descs.push((offset, len, dev_id));
+    }
+
+    /// Returns the number of descriptors in the template
+    pub fn len(&self) -> usize {
+        self.descs.len()
+    }
+
+    /// Returns true if the template has no descriptors
+    pub fn is_empty(&self) -> bool {
+        self.descs.is_empty()
+    }
+
+    /// Builds a transfer descriptor list with every offset resolved against `base`
+    ///
+    /// # Errors
+    /// Returns `NixlError::DescriptorOverflow` if a resolved address does not
+    /// fit in the address space
+    pub fn instantiate<'a>(&self, base: u64) -> Result<XferDescList<'a>, NixlError> {
+        let mut dlist = XferDescList::new(self.mem_type, self.sorted)?;
+        for (index, &(offset, len, dev_id)) in self.descs.iter().enumerate() {
+            let addr = base
+                .checked_add(offset)
+                .and_then(|addr| usize::try_from(addr).ok())
+                .ok_or(NixlError::DescriptorOverflow { index })?;
+            dlist.add_desc(addr, len, dev_id)?;
+        }
+        Ok(dlist)
+    }
+}
@@ -23,6 +23,9 @@ pub struct XferDescList<'a> {
 
 impl<'a> XferDescList<'a> {
     /// Creates a new transfer descriptor list for the given memory type
+    ///
+    /// A sorted list keeps its descriptors ordered by device and address as they
+    /// are added. Use `is_sorted` to read the flag back.
     pub fn new(mem_type: MemType, sorted: bool) -> Result<Self, NixlError> {
         let mut dlist = ptr::null_mut();
         let status = unsafe {
@@ -63,10 +66,59 @@ impl<'a> XferDescList<'a> {
         }
     }
 
-    /// Adds a descriptor to the list
-    pub fn add_desc(&mut self, addr: usize, len: usize, dev_id: u64) -> Result<(), NixlError> {
+    /// Adds a descriptor to the list and returns its index
+    pub fn add_desc(&mut self, addr: usize, len: usize, dev_id: u64) -> Result<usize, NixlError> {
+        let mut index = 0;
+        let status = unsafe {
+            nixl_capi_xfer_dlist_add_desc(
+                self.inner.as_ptr(),
+                addr as uintptr_t,
+                len,
+                dev_id,
+                &mut index,
+            )
+        };
+
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(index),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Creates a transfer descriptor list from (addr, len, dev_id) tuples
+    ///
+    /// The descriptors are added in a single FFI call, see `extend_from_slice`.
+    pub fn from_descs(
+        mem_type: MemType,
+        sorted: bool,
+        descs: &[(usize, usize, u64)],
+    ) -> Result<Self, NixlError> {
+        let mut dlist = Self::new(mem_type, sorted)?;
+        dlist.extend_from_slice(descs)?;
+        Ok(dlist)
+    }
+
+    /// Adds (addr, len, dev_id) tuples to the list in a single FFI call
+    ///
+    /// Unsorted lists reserve room for every descriptor up front. Sorted
+    /// lists insert each descriptor in order, as `add_desc` does.
+    pub fn extend_from_slice(&mut self, descs: &[(usize, usize, u64)]) -> Result<(), NixlError> {
+        let addrs: Vec<uintptr_t> = descs
+            .iter()
+            .map(|&(addr, _, _)| addr as uintptr_t)
+            .collect();
+        let lens: Vec<usize> = descs.iter().map(|&(_, len, _)| len).collect();
+        let dev_ids: Vec<u64> = descs.iter().map(|&(_, _, dev_id)| dev_id).collect();
+
         let status = unsafe {
-            nixl_capi_xfer_dlist_add_desc(self.inner.as_ptr(), addr as uintptr_t, len, dev_id)
+            nixl_capi_xfer_dlist_add_descs(
+                self.inner.as_ptr(),
+                addrs.as_ptr(),
+                lens.as_ptr(),
+                dev_ids.as_ptr(),
+                descs.len(),
+            )
         };
 
         match status {
@@ -76,7 +128,95 @@ impl<'a> XferDescList<'a> {
         }
     }
 
-     /// Returns true if the list is sorted
+    /// Appends the descriptors of another list of the same memory type
+    ///
+    /// A sorted list keeps its order, so `has_overlaps` stays correct.
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if the memory types differ
+    pub fn append(&mut self, other: &XferDescList<'a>) -> Result<(), NixlError> {
+        if self.get_type()? != other.get_type()? {
+            return Err(NixlError::InvalidParam);
+        }
+        let descs = (0..other.len()?)
+            .map(|index| other.get_desc(index))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.extend_from_slice(&descs)
+    }
+
+    /// Splits the list into the descriptors before `index` and those from `index` on
+    ///
+    /// Both lists keep the memory type and sorted flag of this one.
+    ///
+    /// # Errors
+    /// Returns `NixlError::IndexOutOfBounds` if `index` is past the end of the list
+    pub fn split_at(
+        &self,
+        index: usize,
+    ) -> Result<(XferDescList<'a>, XferDescList<'a>), NixlError> {
+        let len = self.len()?;
+        if index > len {
+            return Err(NixlError::IndexOutOfBounds);
+        }
+        let descs = (0..len)
+            .map(|i| self.get_desc(i))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mem_type = self.get_type()?;
+        let sorted = self.is_sorted()?;
+        Ok((
+            Self::from_descs(mem_type, sorted, &descs[..index])?,
+            Self::from_descs(mem_type, sorted, &descs[index..])?,
+        ))
+    }
+
+    /// Returns the (addr, len, dev_id) of the descriptor at `index`
+    pub fn get_desc(&self, index: usize) -> Result<(usize, usize, u64), NixlError> {
+        if index >= self.len()? {
+            return Err(NixlError::IndexOutOfBounds);
+        }
+
+        let mut addr: uintptr_t = 0;
+        let mut len = 0;
+        let mut dev_id = 0;
+        let status = unsafe {
+            nixl_capi_xfer_dlist_get_desc(
+                self.inner.as_ptr(),
+                index,
+                &mut addr,
+                &mut len,
+                &mut dev_id,
+            )
+        };
+
+        match status {
+            NIXL_CAPI_SUCCESS => Ok((addr as usize, len, dev_id)),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Returns the descriptor at `index`
+    pub fn get(&self, index: usize) -> Result<Desc, NixlError> {
+        let (addr, len, dev_id) = self.get_desc(index)?;
+        Ok(Desc {
+            addr,
+            len,
+            dev_id,
+            meta: None,
+        })
+    }
+
+    /// Returns an iterator over the descriptors in the list
+    pub fn iter(&self) -> XferDescIter<'_, 'a> {
+        XferDescIter {
+            list: self,
+            index: 0,
+            done: false,
+        }
+    }
+
+    /// Returns true if the list is sorted
      fn verify_sorted_inner(inner: NonNull<bindings::nixl_capi_xfer_dlist_s>) -> Result<bool, NixlError>   {
         let mut is_sorted = false;
         let status = unsafe { nixl_capi_xfer_dlist_verify_sorted(inner.as_ptr(), &mut is_sorted) };
@@ -120,6 +260,9 @@ impl<'a> XferDescList<'a> {
     }
 
     /// Returns true if any descriptors in the list overlap
+    ///
+    /// Sorted lists only compare neighbouring descriptors, which is linear in
+    /// the list length. Unsorted lists compare every pair.
     pub fn has_overlaps(&self) -> Result<bool, NixlError> {
         let mut has_overlaps = false;
         let status =
@@ -132,7 +275,42 @@ impl<'a> XferDescList<'a> {
         }
     }
 
-    /// Returns true if the list is sorted
+    /// Same as `has_overlaps`, but sorts a copy of the descriptors instead of
+    /// comparing every pair when the list is unsorted
+    ///
+    /// Returns `NixlError::DescriptorOverflow` if a descriptor of an unsorted
+    /// list ends past the address space
+    pub(crate) fn has_overlaps_fast(&self) -> Result<bool, NixlError> {
+        if self.is_sorted()? {
+            return self.has_overlaps();
+        }
+
+        let mut descs = Vec::with_capacity(self.len()?);
+        for index in 0..self.len()? {
+            let (addr, len, dev_id) = self.get_desc(index)?;
+            let end = addr
+                .checked_add(len)
+                .ok_or(NixlError::DescriptorOverflow { index })?;
+            descs.push((dev_id, addr, end));
+        }
+        descs.sort_unstable();
+
+        // Furthest end seen so far on the current device
+        let mut reach: Option<(u64, usize)> = None;
+        for (dev_id, addr, desc_end) in descs {
+            match reach {
+                Some((dev, end)) if dev == dev_id && addr < end => return Ok(true),
+                Some((dev, end)) if dev == dev_id => reach = Some((dev, end.max(desc_end))),
+                _ => reach = Some((dev_id, desc_end)),
+            }
+        }
+        Ok(false)
+    }
+
+    /// Returns true if the list was created sorted or has been sorted since
+    ///
+    /// This reads the flag without inspecting the descriptors. Use
+    /// `verify_sorted` to check the actual order.
     pub fn is_sorted(&self) -> Result<bool, NixlError> {
         let mut is_sorted = false;
         let status = unsafe { nixl_capi_xfer_dlist_is_sorted(self.inner.as_ptr(), &mut is_sorted) };
@@ -197,7 +375,191 @@ impl<'a> XferDescList<'a> {
         }
     }
 
-    /// Add a descriptor from a type implementing NixlDescriptor
+    /// Merges adjacent descriptors on the same device that are contiguous in memory
+    ///
+    /// Returns the number of merges performed.
+    pub fn coalesce(&mut self) -> Result<usize, NixlError> {
+        let mut merged = 0;
+        let status = unsafe { nixl_capi_xfer_dlist_coalesce(self.inner.as_ptr(), &mut merged) };
+
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(merged),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Sorts the descriptors by device and address and marks the list as sorted
+    pub fn sort_by_addr(&mut self) -> Result<(), NixlError> {
+        let status = unsafe { nixl_capi_xfer_dlist_sort(self.inner.as_ptr()) };
+
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(()),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Returns the index of a descriptor whose range contains `addr`
+    ///
+    /// Sorted lists are searched with a binary search per device, which
+    /// assumes descriptors on the same device do not overlap. Unsorted lists
+    /// are scanned in order.
+    pub fn find_covering(&self, addr: u64) -> Result<Option<usize>, NixlError> {
+        let addr = addr as usize;
+        let covers = |(start, len, _): (usize, usize, u64)| start <= addr && addr - start < len;
+        let count = self.len()?;
+
+        if !self.is_sorted()? {
+            for index in 0..count {
+                if covers(self.get_desc(index)?) {
+                    return Ok(Some(index));
+                }
+            }
+            return Ok(None);
+        }
+
+        let mut start = 0;
+        while start < count {
+            let dev_id = self.get_desc(start)?.2;
+            let end = self.partition_point(start, count, |(_, _, dev)| dev == dev_id)?;
+            let index = self.partition_point(start, end, |(desc_addr, _, _)| desc_addr <= addr)?;
+            if index > start && covers(self.get_desc(index - 1)?) {
+                return Ok(Some(index - 1));
+            }
+            start = end;
+        }
+        Ok(None)
+    }
+
+    /// Returns a hash of the memory type, overlap flag and descriptors, in order
+    ///
+    /// The hash is FNV-1a over a fixed byte layout, so identical lists produce
+    /// the same fingerprint in every process and on every Rust version.
+    pub fn fingerprint(&self) -> Result<u64, NixlError> {
+        const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+
+        let mut hash = FNV_OFFSET;
+        let mut feed = |bytes: &[u8]| {
+            for &byte in bytes {
+                hash = (hash ^ byte as u64).wrapping_mul(FNV_PRIME);
+            }
+        };
+
+        feed(&[self.get_type()? as u8, self.has_overlaps()? as u8]);
+        for index in 0..self.len()? {
+            let (addr, len, dev_id) = self.get_desc(index)?;
+            feed(&(addr as u64).to_le_bytes());
+            feed(&(len as u64).to_le_bytes());
+            feed(&dev_id.to_le_bytes());
+        }
+        Ok(hash)
+    }
+
+    /// Checks that every descriptor is usable for a transfer
+    ///
+    /// Lists built from untrusted input should be validated before use: a
+    /// descriptor whose `addr + len` wraps, a zero-length descriptor or an
+    /// exact duplicate of an earlier descriptor is reported with its index.
+    pub fn validate(&self) -> Result<(), NixlError> {
+        let mut seen = HashSet::new();
+        for index in 0..self.len()? {
+            let (addr, len, dev_id) = self.get_desc(index)?;
+            if addr.checked_add(len).is_none() {
+                return Err(NixlError::DescriptorOverflow { index });
+            }
+            if len == 0 {
+                return Err(NixlError::ZeroLengthDescriptor { index });
+            }
+            if !seen.insert((addr, len, dev_id)) {
+                return Err(NixlError::DuplicateDescriptor { index });
+            }
+        }
+        Ok(())
+    }
+
+    /// Describes how this list differs from `other`, e.g. local versus remote
+    ///
+    /// The report lists the memory types, descriptor counts and total lengths
+    /// of both lists followed by every descriptor pair, flagging length
+    /// mismatches and descriptors present in only one list.
+    pub fn diff_report(&self, other: &XferDescList) -> Result<String, NixlError> {
+        use std::fmt::Write;
+
+        let (count, other_count) = (self.len()?, other.len()?);
+        let mut descs = Vec::with_capacity(count);
+        for index in 0..count {
+            descs.push(self.get_desc(index)?);
+        }
+        let mut other_descs = Vec::with_capacity(other_count);
+        for index in 0..other_count {
+            other_descs.push(other.get_desc(index)?);
+        }
+
+        let flag = |equal: bool| if equal { "" } else { "  <- mismatch" };
+        let (mem_type, other_mem_type) = (self.get_type()?, other.get_type()?);
+        let total: usize = descs.iter().map(|d| d.1).sum();
+        let other_total: usize = other_descs.iter().map(|d| d.1).sum();
+
+        let mut report = String::new();
+        let _ = writeln!(
+            report,
+            "mem type: {mem_type:?} | {other_mem_type:?}{}",
+            flag(mem_type == other_mem_type)
+        );
+        let _ = writeln!(
+            report,
+            "descriptors: {count} | {other_count}{}",
+            flag(count == other_count)
+        );
+        let _ = writeln!(
+            report,
+            "total length: {total} | {other_total}{}",
+            flag(total == other_total)
+        );
+
+        let describe = |desc: Option<&(usize, usize, u64)>| match desc {
+            Some((addr, len, dev_id)) => format!("{addr:#x} len {len} dev {dev_id}"),
+            None => "-".to_string(),
+        };
+        for index in 0..count.max(other_count) {
+            let (desc, other_desc) = (descs.get(index), other_descs.get(index));
+            let note = match (desc, other_desc) {
+                (Some(a), Some(b)) if a.1 != b.1 => "  <- length mismatch",
+                (Some(_), Some(_)) => "",
+                _ => "  <- missing",
+            };
+            let _ = writeln!(
+                report,
+                "[{index}] {} | {}{note}",
+                describe(desc),
+                describe(other_desc)
+            );
+        }
+        Ok(report)
+    }
+
+    /// Returns the first index in `lo..hi` for which `pred` is false,
+    /// assuming `pred` holds for a prefix of the range
+    fn partition_point(
+        &self,
+        mut lo: usize,
+        mut hi: usize,
+        pred: impl Fn((usize, usize, u64)) -> bool,
+    ) -> Result<usize, NixlError> {
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if pred(self.get_desc(mid)?) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        Ok(lo)
+    }
+
+    /// Add a descriptor from a type implementing NixlDescriptor and returns its index
     ///
     /// # Safety
     /// The caller must ensure that:
@@ -206,7 +568,7 @@ impl<'a> XferDescList<'a> {
     pub fn add_storage_desc<D: NixlDescriptor + 'a>(
         &mut self,
         desc: &'a D,
-    ) -> Result<(), NixlError> {
+    ) -> Result<usize, NixlError> {
         // Validate memory type matches
         let desc_mem_type = desc.mem_type();
         let list_mem_type = unsafe {
@@ -243,6 +605,22 @@ impl<'a> XferDescList<'a> {
     }
 }
 
+impl fmt::Debug for XferDescList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_struct("XferDescList");
+        if let Ok(mem_type) = self.get_type() {
+            list.field("mem_type", &mem_type);
+        }
+        if let Ok(len) = self.len() {
+            list.field("len", &len);
+        }
+        if let Ok(sorted) = self.is_sorted() {
+            list.field("sorted", &sorted);
+        }
+        list.finish()
+    }
+}
+
 impl Drop for XferDescList<'_> {
     fn drop(&mut self) {
         // SAFETY: self.inner is guaranteed to be valid by NonNull
@@ -251,3 +629,42 @@ impl Drop for XferDescList<'_> {
         }
     }
 }
+
+/// An iterator over the descriptors of a transfer descriptor list
+pub struct XferDescIter<'b, 'a> {
+    list: &'b XferDescList<'a>,
+    index: usize,
+    done: bool,
+}
+
+impl Iterator for XferDescIter<'_, '_> {
+    type Item = Result<Desc, NixlError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let desc = match self.list.len() {
+            Ok(len) if self.index < len => self.list.get(self.index),
+            Ok(_) => {
+                self.done = true;
+                return None;
+            }
+            Err(e) => {
+                self.done = true;
+                Err(e)
+            }
+        };
+        self.index += 1;
+        Some(desc)
+    }
+}
+
+impl<'b, 'a> IntoIterator for &'b XferDescList<'a> {
+    type Item = Result<Desc, NixlError>;
+    type IntoIter = XferDescIter<'b, 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
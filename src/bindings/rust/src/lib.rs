@@ -28,6 +28,7 @@ use std::marker::PhantomData;
 use std::ptr;
 use std::ptr::NonNull;
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 // Include the generated bindings
@@ -41,25 +42,28 @@ mod bindings {
 
 // Re-export types from the included bindings
 use bindings::{
-    nixl_capi_create_agent, nixl_capi_create_backend, nixl_capi_create_notif_map,
+    nixl_capi_create_agent, nixl_capi_create_agent_with_listener, nixl_capi_create_backend, nixl_capi_create_notif_map,
     nixl_capi_create_opt_args, nixl_capi_create_reg_dlist, nixl_capi_create_xfer_dlist,
     nixl_capi_deregister_mem, nixl_capi_destroy_agent, nixl_capi_destroy_backend,
     nixl_capi_destroy_mem_list, nixl_capi_destroy_notif_map, nixl_capi_destroy_opt_args,
     nixl_capi_destroy_params, nixl_capi_destroy_reg_dlist, nixl_capi_destroy_string_list,
     nixl_capi_destroy_xfer_dlist, nixl_capi_get_available_plugins, nixl_capi_get_backend_params,
-    nixl_capi_get_local_md, nixl_capi_get_notifs, nixl_capi_get_plugin_params,
+    nixl_capi_count_notifs, nixl_capi_get_local_md, nixl_capi_get_notifs,
+    nixl_capi_get_plugin_params,
     nixl_capi_get_xfer_status, nixl_capi_invalidate_remote_md, nixl_capi_load_remote_md,
     nixl_capi_mem_list_get, nixl_capi_mem_list_is_empty, nixl_capi_mem_list_size,
     nixl_capi_mem_type_t, nixl_capi_mem_type_to_string, nixl_capi_notif_map_clear,
     nixl_capi_notif_map_get_agent_at, nixl_capi_notif_map_get_notif,
-    nixl_capi_notif_map_get_notifs_size, nixl_capi_notif_map_size, nixl_capi_opt_args_add_backend,
+    nixl_capi_notif_map_get_notifs_size, nixl_capi_notif_map_size, nixl_capi_notif_map_has_agent,
+    nixl_capi_notif_map_erase_agent, nixl_capi_notif_map_add_notif, nixl_capi_opt_args_add_backend,
     nixl_capi_opt_args_get_has_notif, nixl_capi_opt_args_get_notif_msg,
     nixl_capi_opt_args_get_skip_desc_merge, nixl_capi_opt_args_set_has_notif,
     nixl_capi_opt_args_set_notif_msg, nixl_capi_opt_args_set_skip_desc_merge,
     nixl_capi_params_create_iterator, nixl_capi_params_destroy_iterator, nixl_capi_params_is_empty,
     nixl_capi_params_iterator_next, nixl_capi_post_xfer_req, nixl_capi_reg_dlist_add_desc,
-    nixl_capi_reg_dlist_clear, nixl_capi_reg_dlist_has_overlaps, nixl_capi_reg_dlist_len,
-    nixl_capi_reg_dlist_resize, nixl_capi_register_mem, nixl_capi_string_list_get,
+    nixl_capi_reg_dlist_clear, nixl_capi_reg_dlist_get_desc, nixl_capi_reg_dlist_has_overlaps, nixl_capi_reg_dlist_len,
+    nixl_capi_reg_dlist_resize, nixl_capi_reg_dlist_sort, nixl_capi_register_mem,
+    nixl_capi_string_list_get,
     nixl_capi_string_list_size, nixl_capi_xfer_dlist_add_desc, nixl_capi_xfer_dlist_clear,
     nixl_capi_xfer_dlist_has_overlaps, nixl_capi_xfer_dlist_len, nixl_capi_xfer_dlist_resize,
     nixl_capi_agent_make_connection, nixl_capi_reg_dlist_get_type, nixl_capi_reg_dlist_desc_count,
@@ -69,7 +73,9 @@ use bindings::{
     nixl_capi_xfer_dlist_print, nixl_capi_reg_dlist_is_sorted, nixl_capi_gen_notif, nixl_capi_estimate_xfer_cost,
     nixl_capi_query_mem, nixl_capi_create_query_resp_list, nixl_capi_destroy_query_resp_list,
     nixl_capi_query_resp_list_size, nixl_capi_query_resp_list_has_value,
-    nixl_capi_query_resp_list_get_params,
+    nixl_capi_query_resp_list_get_params, nixl_capi_xfer_dlist_coalesce, nixl_capi_xfer_dlist_sort,
+    nixl_capi_xfer_dlist_get_desc, nixl_capi_xfer_dlist_add_descs,
+    nixl_capi_preload_plugins,
 };
 
 // Re-export status codes
@@ -81,19 +87,23 @@ pub use bindings::{
 };
 
 mod agent;
+#[cfg(feature = "cuda")]
+mod cuda;
 mod descriptors;
 mod notify;
 mod utils;
 mod xfer;
 
 pub use agent::*;
+#[cfg(feature = "cuda")]
+pub use cuda::*;
 pub use descriptors::*;
 pub use notify::*;
 pub use utils::*;
 pub use xfer::*;
 
 /// Errors that can occur when using NIXL
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum NixlError {
     #[error("Invalid parameter provided to NIXL")]
     InvalidParam,
@@ -111,6 +121,48 @@ pub enum NixlError {
     RegDescListCreationFailed,
     #[error("Failed to add registration descriptor")]
     RegDescAddFailed,
+    #[error("No backend could be created: {0}")]
+    NoBackendAvailable(String),
+    #[error("Transfer operation not supported by any selected backend")]
+    OpUnsupported,
+    #[error("Metadata of remote agent {0} was invalidated")]
+    RemoteInvalidated(String),
+    #[error("Remote descriptor {index} is not within a registered remote region")]
+    RemoteRegionNotFound { index: usize },
+    #[error("Descriptor exceeds the backend transfer size limit of {limit} bytes")]
+    TransferTooLarge { limit: usize },
+    #[error("Ring buffer has no free slots")]
+    RingBufferFull,
+    #[error("Timed out waiting for the operation to complete")]
+    Timeout,
+    #[error("CRC of descriptor {index} does not match its payload")]
+    CrcMismatch { index: usize },
+    #[error("Destination descriptors overlap")]
+    OverlappingDescriptors,
+    #[error("Descriptor {index} wraps past the end of the address space")]
+    DescriptorOverflow { index: usize },
+    #[error("Descriptor {index} has zero length")]
+    ZeroLengthDescriptor { index: usize },
+    #[error("Descriptor {index} duplicates an earlier descriptor")]
+    DuplicateDescriptor { index: usize },
+    #[error("CUDA error {0}")]
+    CudaError(i32),
+    #[error("Memory must be deregistered in the CUDA context it was registered in")]
+    WrongCudaContext,
+    #[error("GDS error: {0}")]
+    GdsError(String),
+    #[error("Notification message of {len} bytes exceeds the limit of {max} bytes")]
+    NotificationTooLarge { len: usize, max: usize },
+    #[error("Plugin {0} is not available")]
+    PluginNotFound(String),
+    #[error("I/O error: {0}")]
+    Io(std::io::ErrorKind),
+}
+
+impl From<std::io::Error> for NixlError {
+    fn from(e: std::io::Error) -> Self {
+        NixlError::Io(e.kind())
+    }
 }
 
 /// A safe wrapper around NIXL memory list
@@ -127,6 +179,76 @@ impl Drop for MemList {
     }
 }
 
+/// A registered memory region and the user context attached to it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegionInfo {
+    addr: usize,
+    size: usize,
+    dev_id: u64,
+    mem_type: MemType,
+    context: u64,
+}
+
+impl RegionInfo {
+    /// Returns the start address of the region
+    pub fn addr(&self) -> usize {
+        self.addr
+    }
+
+    /// Returns the size of the region in bytes
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the device ID of the region
+    pub fn dev_id(&self) -> u64 {
+        self.dev_id
+    }
+
+    /// Returns the memory type the region was registered as
+    pub fn mem_type(&self) -> MemType {
+        self.mem_type
+    }
+
+    /// Returns the user context given at registration, 0 if none was given
+    pub fn context(&self) -> u64 {
+        self.context
+    }
+
+    /// Returns true if `addr` lies within the region
+    pub fn contains(&self, addr: usize) -> bool {
+        addr >= self.addr && addr - self.addr < self.size
+    }
+}
+
+/// Totals over the memory registered through an agent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegistrationStats {
+    total_bytes: usize,
+    count: usize,
+    backend_registrations: usize,
+}
+
+impl RegistrationStats {
+    /// Returns the number of bytes across all live registrations
+    pub fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+
+    /// Returns the number of live registrations
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns an upper bound on the registrations held by backends
+    ///
+    /// Each backend may register every region on its own, so an RDMA backend
+    /// holds up to one memory region per registration.
+    pub fn backend_registrations(&self) -> usize {
+        self.backend_registrations
+    }
+}
+
 #[derive(Debug)]
 pub struct RegistrationHandle {
     agent: Option<Arc<RwLock<AgentInner>>>,
@@ -134,36 +256,70 @@ pub struct RegistrationHandle {
     size: usize,
     dev_id: u64,
     mem_type: MemType,
+    context: u64,
 }
 
 impl RegistrationHandle {
+    /// Returns the registered region and its user context
+    pub fn info(&self) -> RegionInfo {
+        RegionInfo {
+            addr: self.ptr,
+            size: self.size,
+            dev_id: self.dev_id,
+            mem_type: self.mem_type,
+            context: self.context,
+        }
+    }
+
     pub fn agent_name(&self) -> Option<String> {
         self.agent
             .as_ref()
             .map(|agent| agent.read().unwrap().name.clone())
     }
 
+    /// Detaches the handle so that dropping it does not deregister the memory
+    ///
+    /// The caller becomes responsible for deregistering the region.
+    pub fn leak(mut self) {
+        tracing::trace!(ptr = self.ptr, size = self.size, "Leaking registration");
+        self.agent = None;
+    }
+
     pub fn deregister(&mut self) -> Result<(), NixlError> {
         if let Some(agent) = self.agent.take() {
-            tracing::trace!(
-                ptr = self.ptr,
-                size = self.size,
-                dev_id = self.dev_id,
-                mem_type = ?self.mem_type,
-                "Deregistering memory"
+            self.deregister_from(&mut agent.write().unwrap())?;
+        }
+        Ok(())
+    }
+
+    /// Detaches the handle from its agent without deregistering the memory
+    ///
+    /// The caller deregisters it later with `deregister_from`.
+    pub(crate) fn detach(&mut self) {
+        self.agent = None;
+    }
+
+    /// Deregisters the memory from an agent the caller has already locked
+    pub(crate) fn deregister_from(&self, agent: &mut AgentInner) -> Result<(), NixlError> {
+        tracing::trace!(
+            ptr = self.ptr,
+            size = self.size,
+            dev_id = self.dev_id,
+            mem_type = ?self.mem_type,
+            "Deregistering memory"
+        );
+        let mut reg_dlist = RegDescList::new(self.mem_type, false)?;
+        unsafe {
+            reg_dlist.add_desc(self.ptr, self.size, self.dev_id)?;
+            let _opt_args = OptArgs::new().unwrap();
+            nixl_capi_deregister_mem(
+                agent.handle.as_ptr(),
+                reg_dlist.handle(),
+                _opt_args.inner.as_ptr(),
             );
-            let mut reg_dlist = RegDescList::new(self.mem_type, false)?;
-            unsafe {
-                reg_dlist.add_desc(self.ptr, self.size, self.dev_id)?;
-                let _opt_args = OptArgs::new().unwrap();
-                nixl_capi_deregister_mem(
-                    agent.write().unwrap().handle.as_ptr(),
-                    reg_dlist.handle(),
-                    _opt_args.inner.as_ptr(),
-                );
-            }
-            tracing::trace!("Memory deregistered successfully");
         }
+        agent.remove_region(&self.info());
+        tracing::trace!("Memory deregistered successfully");
         Ok(())
     }
 }
@@ -183,18 +339,133 @@ impl Drop for RegistrationHandle {
     }
 }
 
+/// A registration that borrows its agent and memory for as long as it lives
+///
+/// The borrows make it a compile error to drop or mutably borrow the memory,
+/// or to drop the agent, while the region is registered. The region is
+/// deregistered when the guard is dropped.
+#[derive(Debug)]
+pub struct ScopedRegistration<'a> {
+    handle: RegistrationHandle,
+    _borrows: PhantomData<(&'a Agent, &'a dyn NixlDescriptor)>,
+}
+
+impl ScopedRegistration<'_> {
+    /// Returns the registered region and its user context
+    pub fn info(&self) -> RegionInfo {
+        self.handle.info()
+    }
+
+    /// Deregisters the region now, returning any error instead of logging it
+    pub fn deregister(mut self) -> Result<(), NixlError> {
+        self.handle.deregister()
+    }
+}
+
 /// A NIXL backend that can be used for data transfer
 #[derive(Debug)]
 pub struct Backend {
     inner: NonNull<bindings::nixl_capi_backend_s>,
+    agent: Arc<RwLock<AgentInner>>,
 }
 
 unsafe impl Send for Backend {}
 unsafe impl Sync for Backend {}
 
+impl Backend {
+    /// Returns the memory types this backend can register and transfer
+    pub fn supported_mem_types(&self) -> Result<HashSet<MemType>, NixlError> {
+        let agent = self.agent.read().unwrap();
+        agent.backend_mem_types(self.inner)
+    }
+
+    /// Returns the transfer operations this backend supports for a memory type
+    ///
+    /// NIXL backends service both reads and writes for every memory type they
+    /// register, so this is empty exactly when `mem_type` is unsupported.
+    pub fn supported_ops(&self, mem_type: MemType) -> Result<Vec<XferOp>, NixlError> {
+        let agent = self.agent.read().unwrap();
+        agent.backend_supported_ops(self.inner, mem_type)
+    }
+
+    /// Returns the largest single descriptor this backend takes for a memory type
+    ///
+    /// No NIXL plugin reports a limit of its own. This is the value the caller
+    /// declared through `MAX_TRANSFER_SIZE_PARAM` when creating the backend, and
+    /// `None` means none was declared. The value is read once at creation.
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if the backend does not support `mem_type`
+    pub fn max_transfer_size(&self, mem_type: MemType) -> Result<Option<usize>, NixlError> {
+        let agent = self.agent.read().unwrap();
+        agent.backend_max_transfer_size(self.inner, mem_type)
+    }
+
+    /// Returns the largest number of descriptors to give this backend in one request
+    ///
+    /// No NIXL plugin reports such a limit. This is the value the caller
+    /// declared through `MAX_DESCRIPTORS_PARAM` when creating the backend, for
+    /// batching requests; `None` means none was declared.
+    pub fn max_descriptors(&self) -> Result<Option<usize>, NixlError> {
+        let agent = self.agent.read().unwrap();
+        agent.backend_max_descriptors(self.inner)
+    }
+
+    /// Returns the number of requests posted through the agent that this
+    /// backend is servicing and that have not completed yet
+    ///
+    /// Requests posted by other agents sharing the backend are not counted.
+    pub fn outstanding_ops(&self) -> Result<usize, NixlError> {
+        let mut agent = self.agent.write().unwrap();
+        agent.backend_outstanding_ops(self.inner)
+    }
+
+    /// Returns the queue capacity hint declared for this backend
+    ///
+    /// No NIXL plugin reports a queue capacity. This is the value the caller
+    /// declared through `QUEUE_CAPACITY_PARAM` when creating the backend, for
+    /// use alongside `outstanding_ops`; `None` means none was declared.
+    pub fn queue_capacity(&self) -> Result<Option<usize>, NixlError> {
+        let agent = self.agent.read().unwrap();
+        agent.backend_queue_capacity(self.inner)
+    }
+}
+
+/// Backend parameter declaring the largest single descriptor to give a backend
+///
+/// The bindings remove it before creating the backend, and split or reject
+/// transfers with larger descriptors.
+pub const MAX_TRANSFER_SIZE_PARAM: &str = "max_transfer_size";
+
+/// Backend parameter declaring how many descriptors to give a backend in one request
+///
+/// The bindings remove it before creating the backend; it is returned by
+/// `Backend::max_descriptors`.
+pub const MAX_DESCRIPTORS_PARAM: &str = "max_descriptors";
+
+/// Backend parameter declaring how many operations the caller lets a backend queue
+///
+/// The bindings remove it before creating the backend; it is a hint returned
+/// by `Backend::queue_capacity`.
+pub const QUEUE_CAPACITY_PARAM: &str = "queue_capacity";
+
+/// Largest notification message accepted by `OptArgs::set_notification_message`
+///
+/// NIXL sends a notification as a single backend message, so messages are
+/// bounded up front instead of being handed to the backend at any size.
+pub const MAX_NOTIFICATION_LEN: usize = 64 * 1024;
+
 /// A safe wrapper around NIXL optional arguments
 pub struct OptArgs {
     inner: NonNull<bindings::nixl_capi_opt_args_s>,
+    self_notification: Option<Vec<u8>>,
+    notification_message: Option<Vec<u8>>,
+    append_crc: bool,
+    allow_overlaps: bool,
+    notify_with_length: bool,
+    notify_with_sequence: bool,
+    transform: Option<Arc<dyn DataTransform>>,
+    backends: Vec<NonNull<bindings::nixl_capi_backend_s>>,
 }
 
 impl OptArgs {
@@ -208,7 +479,17 @@ impl OptArgs {
             0 => {
                 // SAFETY: If status is 0, args was successfully created and is non-null
                 let inner = unsafe { NonNull::new_unchecked(args) };
-                Ok(Self { inner })
+                Ok(Self {
+                    inner,
+                    self_notification: None,
+                    notification_message: None,
+                    append_crc: false,
+                    allow_overlaps: false,
+                    notify_with_length: false,
+                    notify_with_sequence: false,
+                    transform: None,
+                    backends: Vec::new(),
+                })
             }
             -1 => Err(NixlError::InvalidParam),
             _ => Err(NixlError::BackendError),
@@ -220,14 +501,32 @@ impl OptArgs {
         let status =
             unsafe { nixl_capi_opt_args_add_backend(self.inner.as_ptr(), backend.inner.as_ptr()) };
         match status {
-            NIXL_CAPI_SUCCESS => Ok(()),
+            NIXL_CAPI_SUCCESS => {
+                self.backends.push(backend.inner);
+                Ok(())
+            }
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
             _ => Err(NixlError::BackendError),
         }
     }
 
+    /// Returns the largest notification message these arguments accept
+    pub fn max_notification_len(&self) -> usize {
+        MAX_NOTIFICATION_LEN
+    }
+
     /// Set the notification message
+    ///
+    /// # Errors
+    /// Returns `NixlError::NotificationTooLarge` if the message is longer
+    /// than `MAX_NOTIFICATION_LEN`
     pub fn set_notification_message(&mut self, message: &[u8]) -> Result<(), NixlError> {
+        if message.len() > MAX_NOTIFICATION_LEN {
+            return Err(NixlError::NotificationTooLarge {
+                len: message.len(),
+                max: MAX_NOTIFICATION_LEN,
+            });
+        }
         let status = unsafe {
             nixl_capi_opt_args_set_notif_msg(
                 self.inner.as_ptr(),
@@ -236,12 +535,20 @@ impl OptArgs {
             )
         };
         match status {
-            NIXL_CAPI_SUCCESS => Ok(()),
+            NIXL_CAPI_SUCCESS => {
+                self.notification_message = Some(message.to_vec());
+                Ok(())
+            }
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
             _ => Err(NixlError::BackendError),
         }
     }
 
+    /// Returns the notification message set on these arguments, without copying it
+    pub fn notification_message(&self) -> Option<&[u8]> {
+        self.notification_message.as_deref()
+    }
+
     /// Get the notification message
     pub fn get_notification_message(&self) -> Result<Vec<u8>, NixlError> {
         let mut data = ptr::null_mut();
@@ -303,6 +610,155 @@ impl OptArgs {
         }
     }
 
+    /// Set a notification to be delivered to the posting agent itself
+    ///
+    /// When these arguments are passed to `post_xfer_req`, the message shows up
+    /// under the posting agent's own name in `get_notifications` once the
+    /// transfer completes.
+    pub fn set_self_notification(&mut self, message: &[u8]) {
+        self.self_notification = Some(message.to_vec());
+    }
+
+    /// Get the self notification message, if one was set
+    pub fn self_notification(&self) -> Option<&[u8]> {
+        self.self_notification.as_deref()
+    }
+
+    /// Set whether transfers carry an inline CRC in the last `CRC_LEN` bytes
+    /// of each local descriptor
+    ///
+    /// When these arguments are passed to `create_xfer_req`, a write stores the
+    /// CRC of each payload in its trailing bytes before posting, and a read
+    /// checks them once `get_xfer_status` reports completion. Descriptors on
+    /// both sides must be sized to include the CRC; the payload is everything
+    /// before it.
+    pub fn set_append_crc(&mut self, append_crc: bool) {
+        self.append_crc = append_crc;
+    }
+
+    /// Get whether transfers carry an inline CRC
+    pub fn append_crc(&self) -> bool {
+        self.append_crc
+    }
+
+    /// Set whether `create_xfer_req` accepts overlapping destination descriptors
+    ///
+    /// The destination is the remote list for writes and the local list for
+    /// reads. When overlapping destinations are allowed, the order in which
+    /// overlapping writes land is defined by the backend.
+    pub fn set_allow_overlaps(&mut self, allow_overlaps: bool) {
+        self.allow_overlaps = allow_overlaps;
+    }
+
+    /// Get whether overlapping destination descriptors are accepted
+    pub fn allow_overlaps(&self) -> bool {
+        self.allow_overlaps
+    }
+
+    /// Set whether the notification of a transfer starts with its length
+    ///
+    /// When these arguments are passed to `create_xfer_req`, notification is
+    /// enabled and the message becomes the total length of the local
+    /// descriptors as an 8-byte little-endian prefix, followed by the message
+    /// set with `set_notification_message`, if any.
+    pub fn set_notify_with_length(&mut self, notify_with_length: bool) {
+        self.notify_with_length = notify_with_length;
+    }
+
+    /// Get whether the notification of a transfer starts with its length
+    pub fn notify_with_length(&self) -> bool {
+        self.notify_with_length
+    }
+
+    /// Set whether the notification of a transfer starts with a sequence number
+    ///
+    /// When these arguments are passed to `create_xfer_req` or `post_xfer_req`,
+    /// notification is enabled and the message starts with an 8-byte
+    /// little-endian number that counts the notifying posts to the same remote
+    /// agent, starting at 0. The number is assigned when the request is
+    /// posted, so a repost takes a new one and failed posts take none. It
+    /// comes before the length prefix of `set_notify_with_length`.
+    ///
+    /// NIXL keeps notifications from one agent in arrival order, but does not
+    /// guarantee that arrival order matches post order across backends or
+    /// workers. `NotificationMap::drain_sender_ordered` restores the order.
+    pub fn set_notify_with_sequence(&mut self, notify_with_sequence: bool) {
+        self.notify_with_sequence = notify_with_sequence;
+    }
+
+    /// Get whether the notification of a transfer starts with a sequence number
+    pub fn notify_with_sequence(&self) -> bool {
+        self.notify_with_sequence
+    }
+
+    /// Set a transform applied to the local data of transfers
+    ///
+    /// When these arguments are passed to `create_xfer_req`, the request is
+    /// staged through a registered scratch buffer: a write encodes the local
+    /// descriptors into it before posting, and a read decodes it into the
+    /// local descriptors once `get_xfer_status` first reports completion.
+    /// Local descriptors must be DRAM.
+    ///
+    /// The transform must keep the length of the data, so it cannot compress
+    /// it; posting or completing the request returns `NixlError::InvalidParam`
+    /// otherwise.
+    pub fn set_transform(&mut self, transform: Arc<dyn DataTransform>) {
+        self.transform = Some(transform);
+    }
+
+    /// Get the transform applied to the local data of transfers, if one was set
+    pub fn transform(&self) -> Option<&Arc<dyn DataTransform>> {
+        self.transform.as_ref()
+    }
+
+    /// Copies these arguments, optionally with other backends and without the notification
+    ///
+    /// `backends` replaces the backend list when given. When `notify` is false
+    /// the copy sends no notification and the notification options are left
+    /// unset.
+    pub(crate) fn copy(
+        &self,
+        backends: Option<&[NonNull<bindings::nixl_capi_backend_s>]>,
+        notify: bool,
+    ) -> Result<Self, NixlError> {
+        let mut args = OptArgs::new()?;
+        for backend in backends.unwrap_or(&self.backends) {
+            // SAFETY: backend was added through add_backend and is owned by its agent
+            let status =
+                unsafe { nixl_capi_opt_args_add_backend(args.inner.as_ptr(), backend.as_ptr()) };
+            if status != NIXL_CAPI_SUCCESS {
+                return Err(NixlError::BackendError);
+            }
+            args.backends.push(*backend);
+        }
+        args.set_skip_descriptor_merge(self.skip_descriptor_merge()?)?;
+
+        if notify {
+            args.set_has_notification(self.has_notification()?)?;
+            args.set_notification_message(&self.get_notification_message()?)?;
+            args.self_notification = self.self_notification.clone();
+            args.notify_with_length = self.notify_with_length;
+            args.notify_with_sequence = self.notify_with_sequence;
+        }
+        args.append_crc = self.append_crc;
+        args.allow_overlaps = self.allow_overlaps;
+        args.transform = self.transform.clone();
+        Ok(args)
+    }
+
+    /// Copies these arguments with `prefix` prepended to the notification message
+    ///
+    /// `prefix` stands in for the length prefix, which the copy no longer adds.
+    pub(crate) fn with_notification_prefix(&self, prefix: &[u8]) -> Result<Self, NixlError> {
+        let mut args = self.copy(None, true)?;
+        let mut message = prefix.to_vec();
+        message.extend(self.get_notification_message()?);
+        args.set_has_notification(true)?;
+        args.set_notification_message(&message)?;
+        args.notify_with_length = false;
+        Ok(args)
+    }
+
     /// Get whether descriptor merging is skipped
     pub fn skip_descriptor_merge(&self) -> Result<bool, NixlError> {
         let mut skip_merge = false;
@@ -316,6 +772,22 @@ impl OptArgs {
     }
 }
 
+impl fmt::Debug for OptArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut args = f.debug_struct("OptArgs");
+        args.field("backends", &self.backends.len());
+        if let Ok(has_notification) = self.has_notification() {
+            args.field("has_notification", &has_notification);
+        }
+        args.field("append_crc", &self.append_crc)
+            .field("allow_overlaps", &self.allow_overlaps)
+            .field("notify_with_length", &self.notify_with_length)
+            .field("notify_with_sequence", &self.notify_with_sequence)
+            .field("transform", &self.transform.is_some())
+            .finish()
+    }
+}
+
 impl Drop for OptArgs {
     fn drop(&mut self) {
         tracing::trace!("Dropping optional arguments");
@@ -431,6 +903,22 @@ pub trait NixlDescriptor: MemoryRegion {
 /// A trait for types that can be registered with NIXL
 pub trait NixlRegistration: NixlDescriptor {
     fn register(&mut self, agent: &Agent, opt_args: Option<&OptArgs>) -> Result<(), NixlError>;
+
+    /// Registers with `agent` through a guard that deregisters when dropped
+    ///
+    /// Unlike `register`, the registration is not stored in `self`, so the
+    /// same memory can be registered with several agents and each guard
+    /// dropped independently. See `Agent::register_scoped`.
+    fn register_scoped<'a>(
+        &'a self,
+        agent: &'a Agent,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<ScopedRegistration<'a>, NixlError>
+    where
+        Self: Sized,
+    {
+        agent.register_scoped(self, opt_args)
+    }
 }
 
 /// System memory storage implementation using a Vec<u8>
@@ -452,10 +940,61 @@ impl SystemStorage {
         self.data.fill(value);
     }
 
+    /// Fill the storage with bytes generated from their index
+    pub fn fill_with<F: FnMut(usize) -> u8>(&mut self, mut f: F) {
+        for (i, byte) in self.data.iter_mut().enumerate() {
+            *byte = f(i);
+        }
+    }
+
     /// Get a slice of the underlying data
     pub fn as_slice(&self) -> &[u8] {
         &self.data
     }
+
+    /// Registers the storage with a user context that is kept with the region
+    pub fn register_with_context(
+        &mut self,
+        agent: &Agent,
+        context: u64,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<(), NixlError> {
+        let handle = agent.register_memory_with_context(self, context, opt_args)?;
+        self.handle = Some(handle);
+        Ok(())
+    }
+
+    /// Returns the registered region, if the storage is registered
+    pub fn region_info(&self) -> Option<RegionInfo> {
+        self.handle.as_ref().map(RegistrationHandle::info)
+    }
+
+    /// Deregisters the storage from `agent`, keeping the allocation usable
+    ///
+    /// Does nothing if the storage is not registered. The storage can be
+    /// registered again afterwards, with the same or another agent.
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if the storage is registered with another agent
+    pub fn deregister(&mut self, agent: &Agent) -> Result<(), NixlError> {
+        match self.handle.take() {
+            None => Ok(()),
+            Some(handle) if !agent.owns_registration(&handle) => {
+                self.handle = Some(handle);
+                Err(NixlError::InvalidParam)
+            }
+            Some(mut handle) => handle.deregister(),
+        }
+    }
+
+    /// Keeps the current registration alive past this storage's drop
+    ///
+    /// The caller becomes responsible for deregistering the memory.
+    pub fn leak_registration(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.leak();
+        }
+    }
 }
 
 impl MemoryRegion for SystemStorage {
@@ -485,3 +1024,195 @@ impl NixlRegistration for SystemStorage {
         Ok(())
     }
 }
+
+/// Alignment required for the size of a device DAX mapping
+///
+/// Device DAX namespaces default to 2 MiB pages; namespaces configured with
+/// 1 GiB pages need sizes that are a multiple of that instead.
+pub const DEV_DAX_ALIGNMENT: usize = 2 * 1024 * 1024;
+
+/// Persistent memory mapped from a device DAX character device, e.g. `/dev/dax0.0`
+///
+/// The mapping always starts at offset 0 and `size` must be a multiple of the
+/// device's alignment (at least `DEV_DAX_ALIGNMENT`), or the mmap fails.
+#[derive(Debug)]
+pub struct DevDaxStorage {
+    ptr: NonNull<u8>,
+    size: usize,
+    handle: Option<RegistrationHandle>,
+}
+
+// SAFETY: The mapping is owned by this storage and only accessed through it
+unsafe impl Send for DevDaxStorage {}
+// SAFETY: Shared access only reads the mapping
+unsafe impl Sync for DevDaxStorage {}
+
+impl DevDaxStorage {
+    /// Maps the first `size` bytes of a device DAX device
+    ///
+    /// # Errors
+    /// Returns `NixlError::InvalidParam` if `size` is zero or not a multiple of
+    /// `DEV_DAX_ALIGNMENT`, and `NixlError::Io` if the device cannot be opened or mapped
+    pub fn open(path: &std::path::Path, size: usize) -> Result<Self, NixlError> {
+        if size == 0 || size & (DEV_DAX_ALIGNMENT - 1) != 0 {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path)?;
+
+        // SAFETY: The file descriptor is valid for the duration of the call;
+        // the mapping outlives it and is unmapped on drop
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED,
+                std::os::fd::AsRawFd::as_raw_fd(&file),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(std::io::Error::last_os_error().into());
+        }
+
+        Ok(Self {
+            ptr: NonNull::new(ptr.cast()).ok_or(NixlError::InvalidDataPointer)?,
+            size,
+            handle: None,
+        })
+    }
+
+    /// Get a slice of the mapped memory
+    pub fn as_slice(&self) -> &[u8] {
+        // SAFETY: The mapping is valid for `size` bytes while self is alive
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.size) }
+    }
+
+    /// Get a mutable slice of the mapped memory
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: The mapping is valid for `size` bytes and borrowed mutably
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.size) }
+    }
+}
+
+impl Drop for DevDaxStorage {
+    fn drop(&mut self) {
+        // Deregister before the memory goes away
+        self.handle.take();
+        // SAFETY: ptr and size describe a mapping created in `open`
+        unsafe {
+            libc::munmap(self.ptr.as_ptr().cast(), self.size);
+        }
+    }
+}
+
+impl MemoryRegion for DevDaxStorage {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    unsafe fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+}
+
+impl NixlDescriptor for DevDaxStorage {
+    fn mem_type(&self) -> MemType {
+        MemType::Dram
+    }
+
+    fn device_id(&self) -> u64 {
+        0
+    }
+}
+
+impl NixlRegistration for DevDaxStorage {
+    fn register(&mut self, agent: &Agent, opt_args: Option<&OptArgs>) -> Result<(), NixlError> {
+        let handle = agent.register_memory(self, opt_args)?;
+        self.handle = Some(handle);
+        Ok(())
+    }
+}
+
+/// An existing file registered as NIXL file storage
+///
+/// The file is described as a single segment starting at offset 0 and
+/// covering the file's size when it was opened. Its file descriptor is the
+/// device ID NIXL file backends such as POSIX and GDS expect.
+#[derive(Debug)]
+pub struct FileStorage {
+    file: std::fs::File,
+    path: std::path::PathBuf,
+    size: usize,
+    handle: Option<RegistrationHandle>,
+}
+
+impl FileStorage {
+    /// Opens an existing file for reading and writing
+    ///
+    /// # Errors
+    /// Returns `NixlError::Io` if the file does not exist or cannot be opened
+    pub fn open(path: &std::path::Path) -> Result<Self, NixlError> {
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path)?;
+        let size = file.metadata()?.len() as usize;
+        Ok(Self {
+            file,
+            path: path.to_path_buf(),
+            size,
+            handle: None,
+        })
+    }
+
+    /// The path the file was opened from
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+
+    /// The file descriptor used as the device ID of the storage
+    pub fn fd(&self) -> i32 {
+        std::os::fd::AsRawFd::as_raw_fd(&self.file)
+    }
+}
+
+impl Drop for FileStorage {
+    fn drop(&mut self) {
+        // Deregister before the file descriptor is closed
+        self.handle.take();
+    }
+}
+
+impl MemoryRegion for FileStorage {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    unsafe fn as_ptr(&self) -> *const u8 {
+        // File segments are addressed by offset, starting at the beginning
+        ptr::null()
+    }
+}
+
+impl NixlDescriptor for FileStorage {
+    fn mem_type(&self) -> MemType {
+        MemType::File
+    }
+
+    fn device_id(&self) -> u64 {
+        self.fd() as u64
+    }
+}
+
+impl NixlRegistration for FileStorage {
+    fn register(&mut self, agent: &Agent, opt_args: Option<&OptArgs>) -> Result<(), NixlError> {
+        let handle = agent.register_memory(self, opt_args)?;
+        self.handle = Some(handle);
+        Ok(())
+    }
+}
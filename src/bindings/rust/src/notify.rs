@@ -20,11 +20,21 @@ use std::{
     ffi::{CStr, CString},
     os::raw::c_char, // Added for *const c_char
     ptr::{self, NonNull},
+    time::Instant,
 };
 
+mod stream;
+
+pub use stream::{NextNotification, NotificationStream, StreamNotification};
+
+/// A notification message with the sequence number it was sent with
+pub type SequencedNotification = (u64, Vec<u8>);
+
 /// A safe wrapper around NIXL notification map
 pub struct NotificationMap {
     pub(crate) inner: NonNull<bindings::nixl_capi_notif_map_s>,
+    /// Retrieval time of each notification, per agent, in map order
+    arrivals: HashMap<String, Vec<Instant>>,
 }
 
 impl NotificationMap {
@@ -36,7 +46,10 @@ impl NotificationMap {
             NIXL_CAPI_SUCCESS => {
                 // SAFETY: If status is NIXL_CAPI_SUCCESS, map is non-null
                 let inner = unsafe { NonNull::new_unchecked(map) };
-                Ok(Self { inner })
+                Ok(Self {
+                    inner,
+                    arrivals: HashMap::new(),
+                })
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
             _ => Err(NixlError::BackendError),
@@ -133,6 +146,166 @@ impl NotificationMap {
         }
     }
 
+    /// Returns true if the map holds notifications from the given agent
+    pub fn contains(&self, agent_name: &str) -> Result<bool, NixlError> {
+        let c_name = CString::new(agent_name).map_err(|_| NixlError::InvalidParam)?;
+        let mut has_agent = false;
+        let status = unsafe {
+            nixl_capi_notif_map_has_agent(self.inner.as_ptr(), c_name.as_ptr(), &mut has_agent)
+        };
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(has_agent),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Returns borrowed views of the notifications from a given agent
+    /// without removing them from the map
+    ///
+    /// Returns `None` if the agent has no notifications in the map.
+    pub fn peek(&self, agent_name: &str) -> Result<Option<Vec<&[u8]>>, NixlError> {
+        if !self.contains(agent_name)? {
+            return Ok(None);
+        }
+
+        let c_name = CString::new(agent_name).map_err(|_| NixlError::InvalidParam)?;
+        let size = self.get_notifications_size(agent_name)?;
+        let mut notifications = Vec::with_capacity(size);
+        for index in 0..size {
+            let mut data: *const std::ffi::c_void = ptr::null();
+            let mut len = 0;
+            let status = unsafe {
+                nixl_capi_notif_map_get_notif(
+                    self.inner.as_ptr(),
+                    c_name.as_ptr(),
+                    index,
+                    &mut data,
+                    &mut len,
+                )
+            };
+            match status {
+                NIXL_CAPI_SUCCESS if data.is_null() || len == 0 => notifications.push(&[][..]),
+                NIXL_CAPI_SUCCESS => {
+                    // SAFETY: data points to len bytes owned by the map, which cannot be
+                    // modified while &self is borrowed
+                    notifications
+                        .push(unsafe { std::slice::from_raw_parts(data as *const u8, len) })
+                }
+                NIXL_CAPI_ERROR_INVALID_PARAM => return Err(NixlError::InvalidParam),
+                _ => return Err(NixlError::BackendError),
+            }
+        }
+        Ok(Some(notifications))
+    }
+
+    /// Removes and returns the notifications from a single agent,
+    /// leaving notifications from other agents in place
+    ///
+    /// Returns `None` if the agent has no notifications in the map.
+    pub fn drain_sender(&mut self, agent_name: &str) -> Result<Option<Vec<Vec<u8>>>, NixlError> {
+        let notifications = match self.peek(agent_name)? {
+            Some(views) => views.into_iter().map(<[u8]>::to_vec).collect(),
+            None => return Ok(None),
+        };
+
+        let c_name = CString::new(agent_name).map_err(|_| NixlError::InvalidParam)?;
+        let status =
+            unsafe { nixl_capi_notif_map_erase_agent(self.inner.as_ptr(), c_name.as_ptr()) };
+        self.arrivals.remove(agent_name);
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(Some(notifications)),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Removes the sequenced notifications from a single agent and returns
+    /// them ordered by sequence number
+    ///
+    /// Each notification must start with the 8-byte sequence number added by
+    /// `OptArgs::set_notify_with_sequence`, which is split off the message.
+    /// Returns `None` if the agent has no notifications in the map.
+    pub fn drain_sender_ordered(
+        &mut self,
+        agent_name: &str,
+    ) -> Result<Option<Vec<SequencedNotification>>, NixlError> {
+        for message in self.peek(agent_name)?.unwrap_or_default() {
+            if message.len() < 8 {
+                return Err(NixlError::InvalidParam);
+            }
+        }
+        let Some(messages) = self.drain_sender(agent_name)? else {
+            return Ok(None);
+        };
+
+        let mut ordered = messages
+            .into_iter()
+            .map(|message| {
+                let mut seq = [0u8; 8];
+                seq.copy_from_slice(&message[..8]);
+                (u64::from_le_bytes(seq), message[8..].to_vec())
+            })
+            .collect::<Vec<_>>();
+        ordered.sort_by_key(|(seq, _)| *seq);
+        Ok(Some(ordered))
+    }
+
+    /// Returns every notification with the time the agent retrieved it,
+    /// oldest first
+    ///
+    /// The time is when the notification was taken from NIXL, not when it
+    /// arrived, so every notification retrieved by one call shares it. Those
+    /// are listed in per-sender arrival order, but the order between senders
+    /// is not their arrival order.
+    pub fn entries_timestamped(&self) -> Result<Vec<(Instant, String, Vec<u8>)>, NixlError> {
+        let mut entries = Vec::new();
+        for agent in self.agents() {
+            let agent = agent?;
+            let arrivals = self.arrivals.get(agent).map_or(&[][..], Vec::as_slice);
+            for (index, message) in self
+                .peek(agent)?
+                .unwrap_or_default()
+                .into_iter()
+                .enumerate()
+            {
+                let at = arrivals
+                    .get(index)
+                    .copied()
+                    .ok_or(NixlError::BackendError)?;
+                entries.push((at, agent.to_string(), message.to_vec()));
+            }
+        }
+        entries.sort_by_key(|(at, _, _)| *at);
+        Ok(entries)
+    }
+
+    /// Stamps notifications of `agent_name` that have no retrieval time yet with `at`
+    pub(crate) fn stamp_arrivals(
+        &mut self,
+        agent_name: &str,
+        at: Instant,
+    ) -> Result<(), NixlError> {
+        let size = self.get_notifications_size(agent_name)?;
+        self.arrivals
+            .entry(agent_name.to_string())
+            .or_default()
+            .resize(size, at);
+        Ok(())
+    }
+
+    /// Stamps every notification that has no retrieval time yet with `at`
+    pub(crate) fn stamp_all_arrivals(&mut self, at: Instant) -> Result<(), NixlError> {
+        let agents = self
+            .agents()
+            .map(|agent| agent.map(str::to_string))
+            .collect::<Result<Vec<_>, _>>()?;
+        for agent in agents {
+            self.stamp_arrivals(&agent, at)?;
+        }
+        Ok(())
+    }
+
     /// Takes all notifications from the map, converting them to Strings,
     /// and clears the underlying C map for reuse.
     ///
@@ -235,6 +408,7 @@ impl NotificationMap {
         }
 
         // After successfully extracting all data, clear the C map
+        self.arrivals.clear();
         let clear_status = unsafe { nixl_capi_notif_map_clear(self.inner.as_ptr()) };
         match clear_status {
             NIXL_CAPI_SUCCESS => Ok(all_notifications),
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::xfer::ProgressDriver;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// An item of a `NotificationStream`: the sending agent's name and the message
+pub type StreamNotification = Result<(String, Vec<u8>), NixlError>;
+
+/// A stream of notifications received by an agent
+///
+/// Yields the sending agent's name and the message, each sender's in arrival
+/// order; notifications from different senders are not ordered. While
+/// nothing is queued the stream is woken periodically by the same background
+/// thread that drives `XferFuture`.
+///
+/// `poll_next` has the signature of `futures::Stream::poll_next` for items
+/// of `StreamNotification`, so the stream can be adapted to that trait
+/// without copying. If the backend reports an error, it is yielded as the
+/// last item before the stream ends.
+pub struct NotificationStream {
+    agent: Agent,
+    finished: bool,
+}
+
+impl NotificationStream {
+    pub(crate) fn new(agent: &Agent) -> Self {
+        Self {
+            agent: agent.clone(),
+            finished: false,
+        }
+    }
+
+    /// Polls for the next notification
+    pub fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<StreamNotification>> {
+        if self.finished {
+            return Poll::Ready(None);
+        }
+        match self.agent.next_notification() {
+            Ok(Some(notif)) => Poll::Ready(Some(Ok(notif))),
+            Ok(None) => {
+                ProgressDriver::wake_later(cx.waker().clone());
+                Poll::Pending
+            }
+            Err(e) => {
+                self.finished = true;
+                Poll::Ready(Some(Err(e)))
+            }
+        }
+    }
+
+    /// Returns a future resolving to the next notification
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> NextNotification<'_> {
+        NextNotification { stream: self }
+    }
+}
+
+/// A future resolving to the next item of a `NotificationStream`
+pub struct NextNotification<'a> {
+    stream: &'a mut NotificationStream,
+}
+
+impl Future for NextNotification<'_> {
+    type Output = Option<StreamNotification>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.stream).poll_next(cx)
+    }
+}
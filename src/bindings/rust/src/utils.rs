@@ -16,8 +16,10 @@
 use super::*;
 
 mod params;
+mod plugin;
 mod string_list;
 
 #[allow(unused)]
 pub use params::*;
+pub use plugin::{preload_plugins, PluginKind};
 pub use string_list::*;
@@ -27,6 +27,116 @@ pub struct ParamPair<'a> {
     pub value: &'a str,
 }
 
+/// A backend parameter with a documented key, parsed into its typed value
+#[derive(Debug, Clone, PartialEq)]
+pub enum KnownParam {
+    /// UCX `num_workers`: number of UCX workers per backend
+    NumWorkers(u32),
+    /// UCX `num_threads`: number of progress threads in the thread pool engine
+    NumThreads(u32),
+    /// UCX `split_batch_size`: descriptors per batch handed to a progress thread
+    SplitBatchSize(usize),
+    /// UCX `device_list`: devices the backend may use
+    DeviceList(Vec<String>),
+    /// UCX `ucx_error_handling_mode`: error handling mode of UCX endpoints
+    UcxErrorHandlingMode(String),
+    /// POSIX `use_aio`: use the Linux AIO queue
+    UseAio(bool),
+    /// POSIX `use_uring`: use the io_uring queue
+    UseUring(bool),
+    /// `max_transfer_size`: largest single descriptor declared for the backend
+    MaxTransferSize(usize),
+    /// A key that is not documented, or whose value could not be parsed
+    Other { key: String, value: String },
+}
+
+impl KnownParam {
+    /// Parses a key-value pair, falling back to `KnownParam::Other`
+    pub fn parse(key: &str, value: &str) -> Self {
+        let parsed = match key {
+            "num_workers" => value.parse().ok().map(KnownParam::NumWorkers),
+            "num_threads" => value.parse().ok().map(KnownParam::NumThreads),
+            "split_batch_size" => value.parse().ok().map(KnownParam::SplitBatchSize),
+            "device_list" => Some(KnownParam::DeviceList(
+                value
+                    .split([',', ' '])
+                    .filter(|dev| !dev.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            )),
+            "ucx_error_handling_mode" => Some(KnownParam::UcxErrorHandlingMode(value.to_string())),
+            "use_aio" => value.parse().ok().map(KnownParam::UseAio),
+            "use_uring" => value.parse().ok().map(KnownParam::UseUring),
+            MAX_TRANSFER_SIZE_PARAM => value.parse().ok().map(KnownParam::MaxTransferSize),
+            _ => None,
+        };
+        parsed.unwrap_or_else(|| KnownParam::Other {
+            key: key.to_string(),
+            value: value.to_string(),
+        })
+    }
+}
+
+/// Typed parameters of the UCX backend, for use with `Agent::create_backend`
+///
+/// Fields left as `None` are not set, so the backend uses its default.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UcxParams {
+    /// Number of UCX workers per backend
+    pub num_workers: Option<u32>,
+    /// Number of progress threads; zero progresses transfers on the caller's thread
+    pub num_threads: Option<u32>,
+    /// Descriptors per batch handed to a progress thread
+    pub split_batch_size: Option<usize>,
+    /// Devices the backend may use, such as `mlx5_0:1`
+    pub device_list: Option<Vec<String>>,
+    /// Error handling mode of UCX endpoints, `"peer"` or `"none"`
+    pub error_handling_mode: Option<String>,
+}
+
+impl UcxParams {
+    /// Reads the UCX parameters out of a parameter set, such as the one
+    /// returned by `Agent::get_plugin_params("UCX")`
+    ///
+    /// Values that cannot be parsed are left as `None`.
+    pub fn from_params(params: &Params) -> Result<Self, NixlError> {
+        let mut ucx = Self::default();
+        for pair in params.iter()? {
+            let pair = pair?;
+            match KnownParam::parse(pair.key, pair.value) {
+                KnownParam::NumWorkers(value) => ucx.num_workers = Some(value),
+                KnownParam::NumThreads(value) => ucx.num_threads = Some(value),
+                KnownParam::SplitBatchSize(value) => ucx.split_batch_size = Some(value),
+                KnownParam::DeviceList(value) => ucx.device_list = Some(value),
+                KnownParam::UcxErrorHandlingMode(value) => ucx.error_handling_mode = Some(value),
+                _ => {}
+            }
+        }
+        Ok(ucx)
+    }
+
+    /// Converts the parameters into a `Params` set for `Agent::create_backend`
+    pub fn into_params(self) -> Result<Params, NixlError> {
+        let mut params = Params::empty()?;
+        if let Some(value) = self.num_workers {
+            params.set("num_workers", &value.to_string())?;
+        }
+        if let Some(value) = self.num_threads {
+            params.set("num_threads", &value.to_string())?;
+        }
+        if let Some(value) = self.split_batch_size {
+            params.set("split_batch_size", &value.to_string())?;
+        }
+        if let Some(value) = self.device_list {
+            params.set("device_list", &value.join(","))?;
+        }
+        if let Some(value) = self.error_handling_mode {
+            params.set("ucx_error_handling_mode", &value)?;
+        }
+        Ok(params)
+    }
+}
+
 /// An iterator over parameter key-value pairs
 pub struct ParamIterator<'a> {
     iter: NonNull<bindings::nixl_capi_param_iter_s>,
@@ -82,6 +192,52 @@ impl Params {
         Self { inner }
     }
 
+    /// Copies the parameters, leaving out the given keys
+    pub(crate) fn without(&self, keys: &[&str]) -> Result<Self, NixlError> {
+        let mut params = Self::empty()?;
+        for pair in self.iter()? {
+            let pair = pair?;
+            if !keys.contains(&pair.key) {
+                params.set(pair.key, pair.value)?;
+            }
+        }
+        Ok(params)
+    }
+
+    /// Creates an empty set of parameters
+    pub fn empty() -> Result<Self, NixlError> {
+        let mut params = ptr::null_mut();
+
+        // SAFETY: params is a valid pointer to write the new handle to
+        let status = unsafe { bindings::nixl_capi_create_params(&mut params) };
+
+        match status {
+            0 => {
+                // SAFETY: If status is 0, params was successfully created and is non-null
+                Ok(Self::new(unsafe { NonNull::new_unchecked(params) }))
+            }
+            -1 => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Sets the value for a key, replacing any existing value
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), NixlError> {
+        let c_key = CString::new(key)?;
+        let c_value = CString::new(value)?;
+
+        // SAFETY: self.inner is guaranteed to be valid by NonNull
+        let status = unsafe {
+            bindings::nixl_capi_params_set(self.inner.as_ptr(), c_key.as_ptr(), c_value.as_ptr())
+        };
+
+        match status {
+            0 => Ok(()),
+            -1 => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
     /// Returns true if the parameters are empty
     pub fn is_empty(&self) -> Result<bool, NixlError> {
         let mut is_empty = false;
@@ -117,6 +273,22 @@ impl Params {
         }
     }
 
+    /// Returns the value for a key, if present
+    pub fn get(&self, key: &str) -> Result<Option<String>, NixlError> {
+        for pair in self.iter()? {
+            let pair = pair?;
+            if pair.key == key {
+                return Ok(Some(pair.value.to_string()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the value for a key parsed into its typed form, if present
+    pub fn get_typed(&self, key: &str) -> Result<Option<KnownParam>, NixlError> {
+        Ok(self.get(key)?.map(|value| KnownParam::parse(key, &value)))
+    }
+
     pub(crate) fn handle(&self) -> *mut bindings::nixl_capi_params_s {
         self.inner.as_ptr()
     }
@@ -0,0 +1,112 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use std::convert::Infallible;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+static PRELOADED_PLUGINS: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Runs plugin discovery ahead of the first agent and returns the loaded plugin names
+///
+/// NIXL discovers plugins once per process, the first time they are needed,
+/// which otherwise makes the first `Agent::new` slow. The names are cached,
+/// so later calls return without crossing into NIXL.
+pub fn preload_plugins() -> Result<Vec<String>, NixlError> {
+    if let Some(plugins) = PRELOADED_PLUGINS.get() {
+        return Ok(plugins.clone());
+    }
+
+    let mut list = ptr::null_mut();
+    let status = unsafe { nixl_capi_preload_plugins(&mut list) };
+    let list = match status {
+        // SAFETY: If status is NIXL_CAPI_SUCCESS, list is non-null
+        NIXL_CAPI_SUCCESS => StringList::new(unsafe { NonNull::new_unchecked(list) }),
+        NIXL_CAPI_ERROR_INVALID_PARAM => return Err(NixlError::InvalidParam),
+        _ => return Err(NixlError::BackendError),
+    };
+
+    let plugins = list
+        .iter()
+        .map(|name| name.map(str::to_string))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(PRELOADED_PLUGINS.get_or_init(|| plugins).clone())
+}
+
+/// Name of a NIXL backend plugin
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PluginKind {
+    Ucx,
+    UcxMo,
+    Posix,
+    Gds,
+    GdsMt,
+    Mooncake,
+    Hf3fs,
+    Obj,
+    Gpunetio,
+    /// A plugin this crate does not know about
+    Other(String),
+}
+
+impl PluginKind {
+    /// Returns the name NIXL uses for the plugin
+    pub fn as_str(&self) -> &str {
+        match self {
+            PluginKind::Ucx => "UCX",
+            PluginKind::UcxMo => "UCX_MO",
+            PluginKind::Posix => "POSIX",
+            PluginKind::Gds => "GDS",
+            PluginKind::GdsMt => "GDS_MT",
+            PluginKind::Mooncake => "Mooncake",
+            PluginKind::Hf3fs => "HF3FS",
+            PluginKind::Obj => "OBJ",
+            PluginKind::Gpunetio => "GPUNETIO",
+            PluginKind::Other(name) => name,
+        }
+    }
+}
+
+impl From<&str> for PluginKind {
+    fn from(name: &str) -> Self {
+        match name {
+            "UCX" => PluginKind::Ucx,
+            "UCX_MO" => PluginKind::UcxMo,
+            "POSIX" => PluginKind::Posix,
+            "GDS" => PluginKind::Gds,
+            "GDS_MT" => PluginKind::GdsMt,
+            "Mooncake" => PluginKind::Mooncake,
+            "HF3FS" => PluginKind::Hf3fs,
+            "OBJ" => PluginKind::Obj,
+            "GPUNETIO" => PluginKind::Gpunetio,
+            other => PluginKind::Other(other.to_string()),
+        }
+    }
+}
+
+impl FromStr for PluginKind {
+    type Err = Infallible;
+
+    fn from_str(name: &str) -> Result<Self, Infallible> {
+        Ok(PluginKind::from(name))
+    }
+}
+
+impl std::fmt::Display for PluginKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -14,14 +14,51 @@
 // limitations under the License.
 
 use super::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
 
+mod batch;
+mod crc;
+mod double_buffer;
+mod future;
+mod group;
+mod ring_buffer;
+mod transform;
+
+pub use batch::BatchHandle;
+pub(crate) use crc::CrcPlan;
+pub use crc::CRC_LEN;
+pub use double_buffer::DoubleBuffer;
+pub(crate) use future::ProgressDriver;
+pub use future::{OwnedXferFuture, XferFuture};
+pub use group::XferGroup;
+pub use ring_buffer::RemoteRingBuffer;
+pub use transform::DataTransform;
+pub(crate) use transform::TransformPlan;
+
+/// Direction of a transfer, as seen from the local agent
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum XferOp {
+    /// Copy remote descriptors into local descriptors
     Read = 0,
+    /// Copy local descriptors into remote descriptors
     Write = 1,
 }
 
+/// State of a transfer request, as reported by `Agent::xfer_state`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum XferState {
+    /// The request was created but has not been posted
+    NotPosted,
+    /// The request was posted and is still transferring
+    InProgress,
+    /// The transfer completed successfully
+    Done,
+    /// The backend reported the transfer as failed
+    Error,
+}
+
 /// Methods used for estimating transfer costs
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -39,23 +76,244 @@ impl From<u32> for CostMethod {
     }
 }
 
+/// Size of a transfer request, as reported by `Agent::xfer_cost`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct XferCost {
+    /// Total bytes moved by the request
+    pub total_bytes: usize,
+    /// Number of descriptor pairs in the request
+    pub descriptor_count: usize,
+    /// Backend estimate as (duration in microseconds, error margin in
+    /// microseconds, cost method), if the backend provides one
+    pub estimate: Option<(i64, i64, CostMethod)>,
+}
+
+/// Latency statistics collected from a series of round trips
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LatencyStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl LatencyStats {
+    /// Computes the statistics from a non-empty set of samples
+    pub(crate) fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let len = samples.len();
+        let p99 = (len * 99).div_ceil(100).saturating_sub(1);
+        Self {
+            min: samples[0],
+            median: samples[len / 2],
+            p99: samples[p99],
+            max: samples[len - 1],
+        }
+    }
+}
+
+/// Description of what a transfer would do, produced by `Agent::dry_run_xfer`
+#[derive(Debug, Clone)]
+pub struct XferPlanReport {
+    pub operation: XferOp,
+    pub remote_agent: String,
+    pub local_mem_type: MemType,
+    pub remote_mem_type: MemType,
+    pub descriptor_count: usize,
+    pub total_bytes: usize,
+    /// The backend NIXL would select, if the transfer is valid
+    pub backend: Option<String>,
+    /// Every problem found while validating the transfer
+    pub problems: Vec<NixlError>,
+}
+
+impl XferPlanReport {
+    /// Returns true if the transfer passed every check
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// The descriptors a transfer request was created from
+#[derive(Debug, Clone)]
+pub(crate) struct XferLayout {
+    pub(crate) operation: XferOp,
+    pub(crate) local_type: MemType,
+    pub(crate) remote_type: MemType,
+    pub(crate) pairs: Vec<(Desc, Desc)>,
+    pub(crate) remote_agent: String,
+}
+
+impl XferLayout {
+    pub(crate) fn new(
+        operation: XferOp,
+        local_descs: &XferDescList,
+        remote_descs: &XferDescList,
+        remote_agent: &str,
+    ) -> Result<Self, NixlError> {
+        let pairs = local_descs
+            .iter()
+            .zip(remote_descs.iter())
+            .map(|(local, remote)| Ok((local?, remote?)))
+            .collect::<Result<_, NixlError>>()?;
+        Ok(Self {
+            operation,
+            local_type: local_descs.get_type()?,
+            remote_type: remote_descs.get_type()?,
+            pairs,
+            remote_agent: remote_agent.to_string(),
+        })
+    }
+
+    /// Total number of bytes moved by the request
+    pub(crate) fn total_len(&self) -> usize {
+        self.pairs.iter().map(|(local, _)| local.len).sum()
+    }
+}
+
 /// A handle to a transfer request
 pub struct XferRequest {
     inner: NonNull<bindings::nixl_capi_xfer_req_s>,
     agent: Arc<RwLock<AgentInner>>,
+    crc: Option<CrcPlan>,
+    transform: Option<TransformPlan>,
+    layout: XferLayout,
+    args: Option<OptArgs>,
+    notification: Option<Vec<u8>>,
+    sequenced: bool,
+    posted: AtomicBool,
+    /// Outcome of decoding and validating the transfer, set on its first
+    /// completion after each post
+    completion: Mutex<Option<Result<(), NixlError>>>,
 }
 
 impl XferRequest {
     pub(crate) fn new(
         inner: NonNull<bindings::nixl_capi_xfer_req_s>,
         agent: Arc<RwLock<AgentInner>>,
-    ) -> Self {
-        Self { inner, agent }
+        crc: Option<CrcPlan>,
+        transform: Option<TransformPlan>,
+        layout: XferLayout,
+        opt_args: Option<&OptArgs>,
+        sequenced: bool,
+    ) -> Result<Self, NixlError> {
+        let args = opt_args.map(|args| args.copy(None, false)).transpose()?;
+        let notification = match opt_args {
+            Some(args) if args.has_notification()? => Some(args.get_notification_message()?),
+            _ => None,
+        };
+        Ok(Self {
+            inner,
+            agent,
+            crc,
+            transform,
+            layout,
+            args,
+            notification,
+            sequenced,
+            posted: AtomicBool::new(false),
+            completion: Mutex::new(None),
+        })
+    }
+
+    /// Returns the name of the backend NIXL chose to service this request
+    pub fn selected_backend(&self) -> Result<String, NixlError> {
+        let agent = self.agent.read().unwrap();
+        for (name, backend) in agent.backends.iter() {
+            let mut selected = false;
+            let status = unsafe {
+                bindings::nixl_capi_query_xfer_backend(
+                    agent.handle.as_ptr(),
+                    self.inner.as_ptr(),
+                    backend.as_ptr(),
+                    &mut selected,
+                )
+            };
+
+            match status {
+                NIXL_CAPI_SUCCESS if selected => return Ok(name.clone()),
+                NIXL_CAPI_SUCCESS => continue,
+                NIXL_CAPI_ERROR_INVALID_PARAM => return Err(NixlError::InvalidParam),
+                _ => return Err(NixlError::BackendError),
+            }
+        }
+        Err(NixlError::BackendError)
     }
 
     pub(crate) fn handle(&self) -> *mut bindings::nixl_capi_xfer_req_s {
         self.inner.as_ptr()
     }
+
+    pub(crate) fn crc(&self) -> Option<&CrcPlan> {
+        self.crc.as_ref()
+    }
+
+    pub(crate) fn transform(&self) -> Option<&TransformPlan> {
+        self.transform.as_ref()
+    }
+
+    pub(crate) fn layout(&self) -> &XferLayout {
+        &self.layout
+    }
+
+    /// The arguments the request was created with, without the notification
+    pub(crate) fn args(&self) -> Option<&OptArgs> {
+        self.args.as_ref()
+    }
+
+    /// The notification a post with `opt_args` sends, without its sequence number
+    ///
+    /// Post arguments replace the notification set at creation, and disable
+    /// it when they carry none, as `nixlAgent::postXferReq` does.
+    pub(crate) fn notification_for(
+        &self,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<Option<Vec<u8>>, NixlError> {
+        let Some(args) = opt_args else {
+            return Ok(self.notification.clone());
+        };
+        if !args.has_notification()? && !args.notify_with_length() && !args.notify_with_sequence() {
+            return Ok(None);
+        }
+        let mut message = Vec::new();
+        if args.notify_with_length() {
+            message.extend_from_slice(&(self.layout.total_len() as u64).to_le_bytes());
+        }
+        message.extend(args.get_notification_message()?);
+        Ok(Some(message))
+    }
+
+    /// Whether a post with `opt_args` prefixes the notification with a sequence number
+    pub(crate) fn sequenced_for(&self, opt_args: Option<&OptArgs>) -> bool {
+        self.sequenced || opt_args.is_some_and(|args| args.notify_with_sequence())
+    }
+
+    /// Returns true once the request has been posted successfully
+    pub(crate) fn is_posted(&self) -> bool {
+        self.posted.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn mark_posted(&self) {
+        *self.completion.lock().unwrap() = None;
+        self.posted.store(true, Ordering::Release);
+    }
+
+    /// Decodes and validates the completed transfer, once per post
+    ///
+    /// Later calls return the first outcome without touching the local
+    /// buffers again.
+    pub(crate) fn complete(&self) -> Result<(), NixlError> {
+        self.completion
+            .lock()
+            .unwrap()
+            .get_or_insert_with(|| {
+                self.transform
+                    .as_ref()
+                    .map_or(Ok(()), TransformPlan::decode)
+                    .and_then(|()| self.crc.as_ref().map_or(Ok(()), CrcPlan::validate))
+            })
+            .clone()
+    }
 }
 
 // SAFETY: XferRequest can be sent between threads safely
@@ -63,15 +321,50 @@ unsafe impl Send for XferRequest {}
 // SAFETY: XferRequest can be shared between threads safely
 unsafe impl Sync for XferRequest {}
 
+impl fmt::Debug for XferRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("XferRequest")
+            .field("operation", &self.layout.operation)
+            .field("remote_agent", &self.layout.remote_agent)
+            .field("descriptors", &self.layout.pairs.len())
+            .field("bytes", &self.layout.total_len())
+            .finish()
+    }
+}
+
 impl Drop for XferRequest {
     fn drop(&mut self) {
+        let mut agent = self.agent.write().unwrap();
+        let (handle, req) = (agent.handle.as_ptr(), self.inner.as_ptr());
+        while let Some(index) = agent.self_notifs.iter().position(|(r, _)| *r == req) {
+            let (_, message) = agent.self_notifs.remove(index);
+            // A completed transfer keeps its self notification for the next
+            // get_notifications
+            if unsafe { bindings::nixl_capi_get_xfer_status(handle, req) } == NIXL_CAPI_SUCCESS {
+                let name = agent.name.clone();
+                agent
+                    .pending_notifs
+                    .push_back((Instant::now(), name, message));
+            }
+        }
+        agent.posted.retain(|req| *req != self.inner.as_ptr());
         unsafe {
-            bindings::nixl_capi_release_xfer_req(
-                self.agent.write().unwrap().handle.as_ptr(),
-                self.inner.as_ptr(),
-            );
-
-            bindings::nixl_capi_destroy_xfer_req(self.inner.as_ptr());
+            let released = bindings::nixl_capi_release_xfer_req(handle, req) == NIXL_CAPI_SUCCESS;
+            if !released && bindings::nixl_capi_get_xfer_status(handle, req) == NIXL_CAPI_IN_PROG {
+                // The backend could not cancel the transfer, so the agent releases
+                // it once it finishes, along with the scratch buffer it uses
+                let transform = self.transform.take().map(|mut plan| {
+                    plan.detach();
+                    plan
+                });
+                agent.deferred.push((req, transform));
+            } else {
+                if !released {
+                    bindings::nixl_capi_release_xfer_req(handle, req);
+                }
+                bindings::nixl_capi_destroy_xfer_req(req);
+            }
         }
+        agent.release_deferred();
     }
 }
@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Transfer requests posted together by `Agent::post_batch`
+pub struct BatchHandle<'a> {
+    agent: Agent,
+    requests: Vec<&'a XferRequest>,
+    pending: Vec<bool>,
+}
+
+impl<'a> BatchHandle<'a> {
+    pub(crate) fn new(agent: &Agent, requests: Vec<&'a XferRequest>, pending: Vec<bool>) -> Self {
+        Self {
+            agent: agent.clone(),
+            requests,
+            pending,
+        }
+    }
+
+    /// Returns the number of requests in the batch
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Returns true if the batch has no requests
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Checks every request still in progress and returns true once all have completed
+    pub fn poll(&mut self) -> Result<bool, NixlError> {
+        for (req, pending) in self.requests.iter().zip(self.pending.iter_mut()) {
+            if *pending {
+                *pending = self.agent.get_xfer_status(req)?;
+            }
+        }
+        Ok(!self.pending.contains(&true))
+    }
+
+    /// Blocks until every request in the batch has completed
+    pub fn wait_all(&mut self) -> Result<(), NixlError> {
+        while !self.poll()? {
+            std::thread::yield_now();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Number of bytes reserved at the end of each descriptor for an inline CRC
+pub const CRC_LEN: usize = 4;
+
+/// Local descriptors whose trailing bytes carry a CRC of the preceding payload
+#[derive(Debug, Clone)]
+pub(crate) struct CrcPlan {
+    operation: XferOp,
+    regions: Vec<(usize, usize)>,
+}
+
+impl CrcPlan {
+    /// Captures the local regions of a transfer, which must be DRAM and each
+    /// large enough to hold the CRC
+    pub(crate) fn new(operation: XferOp, local_descs: &XferDescList) -> Result<Self, NixlError> {
+        if local_descs.get_type()? != MemType::Dram {
+            return Err(NixlError::InvalidParam);
+        }
+        let mut regions = Vec::with_capacity(local_descs.len()?);
+        for index in 0..local_descs.len()? {
+            let (addr, len, _) = local_descs.get_desc(index)?;
+            if len < CRC_LEN {
+                return Err(NixlError::InvalidParam);
+            }
+            regions.push((addr, len));
+        }
+        Ok(Self { operation, regions })
+    }
+
+    /// Writes the CRC of each payload into its trailing bytes before a write
+    pub(crate) fn append(&self) {
+        if self.operation != XferOp::Write {
+            return;
+        }
+        for &(addr, len) in &self.regions {
+            // SAFETY: The region was described by the caller as local DRAM of len bytes
+            let buf = unsafe { std::slice::from_raw_parts_mut(addr as *mut u8, len) };
+            let (payload, tail) = buf.split_at_mut(len - CRC_LEN);
+            tail.copy_from_slice(&crc32(payload).to_le_bytes());
+        }
+    }
+
+    /// Checks the trailing CRC of each payload after a read completes
+    pub(crate) fn validate(&self) -> Result<(), NixlError> {
+        if self.operation != XferOp::Read {
+            return Ok(());
+        }
+        for (index, &(addr, len)) in self.regions.iter().enumerate() {
+            // SAFETY: The region was described by the caller as local DRAM of len bytes
+            let buf = unsafe { std::slice::from_raw_parts(addr as *const u8, len) };
+            let (payload, tail) = buf.split_at(len - CRC_LEN);
+            if tail != crc32(payload).to_le_bytes() {
+                return Err(NixlError::CrcMismatch { index });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// CRC-32 (IEEE 802.3) of a byte slice
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Two registered buffers used alternately for streaming transfers
+///
+/// While one buffer is being transferred, the other can be filled with the
+/// next chunk of data.
+pub struct DoubleBuffer {
+    agent: Agent,
+    buffers: [SystemStorage; 2],
+    current: usize,
+    pending: Option<XferRequest>,
+}
+
+impl DoubleBuffer {
+    /// Allocates and registers two buffers of `size` bytes with the agent
+    pub fn new(agent: &Agent, size: usize, opt_args: Option<&OptArgs>) -> Result<Self, NixlError> {
+        let mut buffers = [SystemStorage::new(size)?, SystemStorage::new(size)?];
+        for buffer in buffers.iter_mut() {
+            buffer.register(agent, opt_args)?;
+        }
+
+        Ok(Self {
+            agent: agent.clone(),
+            buffers,
+            current: 0,
+            pending: None,
+        })
+    }
+
+    /// Returns the buffer that is currently free to be filled
+    pub fn current(&mut self) -> &mut SystemStorage {
+        &mut self.buffers[self.current]
+    }
+
+    /// Posts a transfer of the current buffer and returns the other one for filling
+    ///
+    /// The previously posted transfer is awaited first, so the returned buffer
+    /// is no longer in use by NIXL.
+    ///
+    /// # Arguments
+    /// * `op` - The transfer operation
+    /// * `remote` - Remote descriptors for this chunk
+    /// * `peer` - The name of the remote agent
+    pub fn fill_and_swap(
+        &mut self,
+        op: XferOp,
+        remote: &XferDescList,
+        peer: &str,
+    ) -> Result<&mut SystemStorage, NixlError> {
+        let req = {
+            let mut local = XferDescList::new(MemType::Dram, false)?;
+            local.add_storage_desc(&self.buffers[self.current])?;
+            let req = self.agent.create_xfer_req(op, &local, remote, peer, None)?;
+            self.agent.post_xfer_req(&req, None)?;
+            req
+        };
+
+        self.finish()?;
+        self.pending = Some(req);
+        self.current ^= 1;
+        Ok(&mut self.buffers[self.current])
+    }
+
+    /// Waits for the outstanding transfer, if any, to complete
+    pub fn finish(&mut self) -> Result<(), NixlError> {
+        if let Some(req) = self.pending.take() {
+            while self.agent.get_xfer_status(&req)? {
+                std::thread::yield_now();
+            }
+        }
+        Ok(())
+    }
+}
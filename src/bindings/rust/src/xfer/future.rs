@@ -0,0 +1,150 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Condvar, Mutex, Once};
+use std::task::{Context, Poll, Waker};
+
+/// Delay before a pending transfer future is woken to poll its status again
+const PROGRESS_INTERVAL: Duration = Duration::from_micros(100);
+
+/// A future that resolves when a posted transfer request completes
+///
+/// Each poll checks the request status. While the transfer is in progress the
+/// future hands its waker to a shared background thread, which wakes it again
+/// after a short interval instead of busy-spinning.
+///
+/// Dropping the future before it resolves does not cancel the transfer; the
+/// request keeps running and can still be checked with `get_xfer_status`.
+pub struct XferFuture<'a> {
+    agent: Agent,
+    req: &'a XferRequest,
+}
+
+impl<'a> XferFuture<'a> {
+    pub(crate) fn new(agent: &Agent, req: &'a XferRequest) -> Self {
+        Self {
+            agent: agent.clone(),
+            req,
+        }
+    }
+}
+
+impl Future for XferFuture<'_> {
+    type Output = Result<(), NixlError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.agent.get_xfer_status(self.req) {
+            Ok(true) => {
+                ProgressDriver::wake_later(cx.waker().clone());
+                Poll::Pending
+            }
+            Ok(false) => Poll::Ready(Ok(())),
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
+
+/// A future that owns a transfer's descriptor lists and returns them on completion
+///
+/// Created by `Agent::post_owned`. Resolves to the local and remote lists
+/// once the transfer completes, so they can be cleared and reused. An error
+/// creating, posting or checking the transfer is returned instead, and the
+/// lists are dropped.
+pub struct OwnedXferFuture<'a> {
+    agent: Agent,
+    state: Option<OwnedXfer<'a>>,
+}
+
+type OwnedXfer<'a> = Result<(XferRequest, XferDescList<'a>, XferDescList<'a>), NixlError>;
+
+impl<'a> OwnedXferFuture<'a> {
+    pub(crate) fn new(agent: &Agent, state: OwnedXfer<'a>) -> Self {
+        Self {
+            agent: agent.clone(),
+            state: Some(state),
+        }
+    }
+}
+
+impl<'a> Future for OwnedXferFuture<'a> {
+    type Output = Result<(XferDescList<'a>, XferDescList<'a>), NixlError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let status = match this.state.as_ref() {
+            Some(Ok((req, _, _))) => this.agent.get_xfer_status(req),
+            Some(Err(_)) => Ok(false),
+            None => panic!("OwnedXferFuture polled after completion"),
+        };
+        match status {
+            Ok(true) => {
+                ProgressDriver::wake_later(cx.waker().clone());
+                Poll::Pending
+            }
+            Ok(false) => {
+                let state = this.state.take().unwrap();
+                Poll::Ready(state.map(|(_req, local, remote)| (local, remote)))
+            }
+            Err(e) => {
+                this.state = None;
+                Poll::Ready(Err(e))
+            }
+        }
+    }
+}
+
+/// Background thread waking pending transfer futures and notification streams
+pub(crate) struct ProgressDriver {
+    wakers: Mutex<Vec<Waker>>,
+    ready: Condvar,
+}
+
+static DRIVER: ProgressDriver = ProgressDriver {
+    wakers: Mutex::new(Vec::new()),
+    ready: Condvar::new(),
+};
+
+impl ProgressDriver {
+    /// Queues a waker to be woken after `PROGRESS_INTERVAL`
+    pub(crate) fn wake_later(waker: Waker) {
+        static START: Once = Once::new();
+        START.call_once(|| {
+            std::thread::Builder::new()
+                .name("nixl-progress".to_string())
+                .spawn(|| DRIVER.run())
+                .expect("Failed to spawn the NIXL progress thread");
+        });
+
+        DRIVER.wakers.lock().unwrap().push(waker);
+        DRIVER.ready.notify_one();
+    }
+
+    fn run(&self) {
+        loop {
+            let mut wakers = self.wakers.lock().unwrap();
+            while wakers.is_empty() {
+                wakers = self.ready.wait(wakers).unwrap();
+            }
+            let pending = std::mem::take(&mut *wakers);
+            drop(wakers);
+
+            std::thread::sleep(PROGRESS_INTERVAL);
+            pending.into_iter().for_each(Waker::wake);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A group of posted transfer requests that can be awaited as they complete
+pub struct XferGroup {
+    agent: Agent,
+    requests: Vec<XferRequest>,
+    completed: Vec<bool>,
+}
+
+impl XferGroup {
+    /// Creates an empty group for requests of the given agent
+    pub fn new(agent: &Agent) -> Self {
+        Self {
+            agent: agent.clone(),
+            requests: Vec::new(),
+            completed: Vec::new(),
+        }
+    }
+
+    /// Adds a posted transfer request and returns its index in the group
+    pub fn add(&mut self, req: XferRequest) -> usize {
+        self.requests.push(req);
+        self.completed.push(false);
+        self.requests.len() - 1
+    }
+
+    /// Gets a request by its index in the group
+    pub fn get(&self, index: usize) -> Option<&XferRequest> {
+        self.requests.get(index)
+    }
+
+    /// Returns the number of requests in the group
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Returns true if the group has no requests
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Returns true if every request in the group has been reported as completed
+    pub fn is_done(&self) -> bool {
+        self.completed.iter().all(|&done| done)
+    }
+
+    /// Returns the indices of requests that completed since the last poll
+    ///
+    /// Each request is reported exactly once.
+    pub fn poll_completed(&mut self) -> Result<Vec<usize>, NixlError> {
+        let mut newly_completed = Vec::new();
+        for (index, req) in self.requests.iter().enumerate() {
+            if !self.completed[index] && !self.agent.get_xfer_status(req)? {
+                self.completed[index] = true;
+                newly_completed.push(index);
+            }
+        }
+        Ok(newly_completed)
+    }
+}
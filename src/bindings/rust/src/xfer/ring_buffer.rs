@@ -0,0 +1,215 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use std::collections::BTreeMap;
+
+/// Prefix of the notification announcing a committed slot
+const ENTRY_PREFIX: &str = "nixl_ring:";
+/// Prefix of the notification acknowledging consumed slots
+const ACK_PREFIX: &str = "nixl_ring_ack:";
+
+enum Role {
+    Producer {
+        head: u64,
+        tail: u64,
+    },
+    Consumer {
+        next: u64,
+        pending: BTreeMap<u64, (usize, usize)>,
+    },
+}
+
+/// A ring of fixed-size slots streamed from a producer agent to a consumer agent
+///
+/// The producer writes messages into its registered ring and announces each
+/// committed slot with a notification. The consumer reads the slot with a
+/// transfer and acknowledges it, which frees the slot for reuse.
+///
+/// Both sides must have loaded each other's metadata. While the ring is in
+/// use, its notifications from the peer are consumed by the ring; any other
+/// notification stays queued for `Agent::get_notifications`.
+pub struct RemoteRingBuffer {
+    agent: Agent,
+    peer: String,
+    slot_size: usize,
+    slots: usize,
+    storage: SystemStorage,
+    role: Role,
+}
+
+impl RemoteRingBuffer {
+    /// Creates the producer side of a ring with `slots` slots of `slot_size` bytes
+    ///
+    /// # Arguments
+    /// * `agent` - The producing agent
+    /// * `peer` - The name of the consuming agent
+    /// * `slot_size` - The maximum size of a single message
+    /// * `slots` - The number of messages that can be in flight
+    pub fn producer(
+        agent: &Agent,
+        peer: &str,
+        slot_size: usize,
+        slots: usize,
+    ) -> Result<Self, NixlError> {
+        if slots == 0 {
+            return Err(NixlError::InvalidParam);
+        }
+        Self::new(
+            agent,
+            peer,
+            slot_size,
+            slots,
+            Role::Producer { head: 0, tail: 0 },
+        )
+    }
+
+    /// Creates the consumer side of a ring whose slots hold up to `slot_size` bytes
+    ///
+    /// # Arguments
+    /// * `agent` - The consuming agent
+    /// * `peer` - The name of the producing agent
+    /// * `slot_size` - The slot size used by the producer
+    pub fn consumer(agent: &Agent, peer: &str, slot_size: usize) -> Result<Self, NixlError> {
+        let role = Role::Consumer {
+            next: 0,
+            pending: BTreeMap::new(),
+        };
+        Self::new(agent, peer, slot_size, 1, role)
+    }
+
+    fn new(
+        agent: &Agent,
+        peer: &str,
+        slot_size: usize,
+        slots: usize,
+        role: Role,
+    ) -> Result<Self, NixlError> {
+        if slot_size == 0 {
+            return Err(NixlError::InvalidParam);
+        }
+        let mut storage = SystemStorage::new(slot_size * slots)?;
+        storage.register(agent, None)?;
+
+        Ok(Self {
+            agent: agent.clone(),
+            peer: peer.to_string(),
+            slot_size,
+            slots,
+            storage,
+            role,
+        })
+    }
+
+    /// Copies `data` into the next free slot and announces it to the consumer
+    ///
+    /// Returns `NixlError::RingBufferFull` if the consumer has not yet
+    /// acknowledged enough slots, and `NixlError::InvalidParam` if `data` does
+    /// not fit in a slot or this is the consumer side.
+    pub fn push(&mut self, data: &[u8]) -> Result<(), NixlError> {
+        if data.len() > self.slot_size || !matches!(self.role, Role::Producer { .. }) {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let messages = self.poll()?;
+        let Role::Producer { head, tail } = &mut self.role else {
+            unreachable!()
+        };
+        for message in messages {
+            if let Some(acked) = message.strip_prefix(ACK_PREFIX) {
+                let acked = acked.parse().map_err(|_| NixlError::BackendError)?;
+                *tail = (*tail).max(acked);
+            }
+        }
+        if *head - *tail == self.slots as u64 {
+            return Err(NixlError::RingBufferFull);
+        }
+
+        let offset = (*head % self.slots as u64) as usize * self.slot_size;
+        self.storage.data[offset..offset + data.len()].copy_from_slice(data);
+        let addr = unsafe { self.storage.as_ptr() } as usize + offset;
+        let entry = format!("{ENTRY_PREFIX}{head}:{addr}:{}", data.len());
+        self.agent
+            .send_notification(&self.peer, entry.as_bytes(), None)?;
+        *head += 1;
+        Ok(())
+    }
+
+    /// Reads the next committed message from the producer
+    ///
+    /// Returns `Ok(None)` if no new message has been announced yet.
+    /// Returns `NixlError::InvalidParam` if this is the producer side.
+    pub fn pop(&mut self) -> Result<Option<Vec<u8>>, NixlError> {
+        if !matches!(self.role, Role::Consumer { .. }) {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let messages = self.poll()?;
+        let Role::Consumer { next, pending } = &mut self.role else {
+            unreachable!()
+        };
+        for message in messages {
+            if let Some(entry) = message.strip_prefix(ENTRY_PREFIX) {
+                let fields = entry
+                    .split(':')
+                    .map(str::parse)
+                    .collect::<Result<Vec<usize>, _>>()
+                    .map_err(|_| NixlError::BackendError)?;
+                let [seq, addr, len] = fields[..] else {
+                    return Err(NixlError::BackendError);
+                };
+                pending.insert(seq as u64, (addr, len));
+            }
+        }
+
+        let Some((addr, len)) = pending.remove(next) else {
+            return Ok(None);
+        };
+
+        if len > 0 {
+            let mut local = XferDescList::new(MemType::Dram, false)?;
+            local.add_desc(unsafe { self.storage.as_ptr() } as usize, len, 0)?;
+            let mut remote = XferDescList::new(MemType::Dram, false)?;
+            remote.add_desc(addr, len, 0)?;
+
+            let req =
+                self.agent
+                    .create_xfer_req(XferOp::Read, &local, &remote, &self.peer, None)?;
+            if self.agent.post_xfer_req(&req, None)? {
+                while self.agent.get_xfer_status(&req)? {
+                    std::thread::yield_now();
+                }
+            }
+        }
+
+        *next += 1;
+        let ack = format!("{ACK_PREFIX}{next}");
+        self.agent
+            .send_notification(&self.peer, ack.as_bytes(), None)?;
+        Ok(Some(self.storage.data[..len].to_vec()))
+    }
+
+    /// Takes the ring notifications received from the peer
+    fn poll(&mut self) -> Result<Vec<String>, NixlError> {
+        let messages = self.agent.take_notifications_from(&self.peer, |message| {
+            message.starts_with(ENTRY_PREFIX.as_bytes())
+                || message.starts_with(ACK_PREFIX.as_bytes())
+        })?;
+        Ok(messages
+            .into_iter()
+            .filter_map(|message| String::from_utf8(message).ok())
+            .collect())
+    }
+}
@@ -0,0 +1,164 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A reversible transformation of transferred data, such as encryption
+///
+/// Encoded data must be the same length as its source, since the remote
+/// descriptors are fixed when the request is created. Compression is
+/// therefore not possible.
+pub trait DataTransform: Send + Sync {
+    /// Transforms local data before it is written to the remote agent
+    fn encode(&self, src: &[u8]) -> Vec<u8>;
+
+    /// Restores data read from the remote agent
+    fn decode(&self, src: &[u8]) -> Vec<u8>;
+}
+
+/// Local descriptors staged through a registered scratch buffer so that a
+/// transform can be applied between the caller's memory and the wire
+pub(crate) struct TransformPlan {
+    operation: XferOp,
+    transform: Arc<dyn DataTransform>,
+    regions: Vec<(usize, usize)>,
+    scratch: SystemStorage,
+}
+
+impl fmt::Debug for TransformPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransformPlan")
+            .field("operation", &self.operation)
+            .field("regions", &self.regions.len())
+            .finish()
+    }
+}
+
+impl TransformPlan {
+    /// Captures the local regions of a transfer, which must be DRAM, and
+    /// registers a scratch buffer covering all of them
+    ///
+    /// Returns the plan with the descriptors the request should be created
+    /// against, which point into the scratch buffer.
+    pub(crate) fn new(
+        agent: &Agent,
+        operation: XferOp,
+        transform: Arc<dyn DataTransform>,
+        local_descs: &XferDescList,
+    ) -> Result<(Self, XferDescList<'static>), NixlError> {
+        if local_descs.get_type()? != MemType::Dram {
+            return Err(NixlError::InvalidParam);
+        }
+        let mut regions = Vec::with_capacity(local_descs.len()?);
+        for index in 0..local_descs.len()? {
+            let (addr, len, _) = local_descs.get_desc(index)?;
+            regions.push((addr, len));
+        }
+
+        let mut scratch = SystemStorage::new(regions.iter().map(|&(_, len)| len).sum())?;
+        scratch.register(agent, None)?;
+
+        // SAFETY: The scratch buffer lives as long as the plan, which the request owns
+        let base = unsafe { scratch.as_ptr() } as usize;
+        let mut staged = XferDescList::new(MemType::Dram, false)?;
+        let mut offset = 0;
+        for &(_, len) in &regions {
+            staged.add_desc(base + offset, len, 0)?;
+            offset += len;
+        }
+
+        let plan = Self {
+            operation,
+            transform,
+            regions,
+            scratch,
+        };
+        Ok((plan, staged))
+    }
+
+    /// Encodes each local region into the scratch buffer before a write
+    ///
+    /// Returns `NixlError::InvalidParam` if the transform changes the length of a region.
+    pub(crate) fn encode(&self) -> Result<(), NixlError> {
+        if self.operation != XferOp::Write {
+            return Ok(());
+        }
+        let mut offset = 0;
+        for &(addr, len) in &self.regions {
+            // SAFETY: The region was described by the caller as local DRAM of len bytes
+            let src = unsafe { std::slice::from_raw_parts(addr as *const u8, len) };
+            let encoded = self.transform.encode(src);
+            if encoded.len() != len {
+                return Err(NixlError::InvalidParam);
+            }
+            // SAFETY: The scratch buffer holds every region back to back
+            let dst = unsafe { self.scratch_region(offset, len) };
+            dst.copy_from_slice(&encoded);
+            offset += len;
+        }
+        Ok(())
+    }
+
+    /// Decodes the scratch buffer into each local region after a read completes
+    ///
+    /// Returns `NixlError::InvalidParam` if the transform changes the length of a region.
+    pub(crate) fn decode(&self) -> Result<(), NixlError> {
+        if self.operation != XferOp::Read {
+            return Ok(());
+        }
+        let mut offset = 0;
+        for &(addr, len) in &self.regions {
+            // SAFETY: The scratch buffer holds every region back to back
+            let src = unsafe { self.scratch_region(offset, len) };
+            let decoded = self.transform.decode(src);
+            if decoded.len() != len {
+                return Err(NixlError::InvalidParam);
+            }
+            // SAFETY: The region was described by the caller as local DRAM of len bytes
+            let dst = unsafe { std::slice::from_raw_parts_mut(addr as *mut u8, len) };
+            dst.copy_from_slice(&decoded);
+            offset += len;
+        }
+        Ok(())
+    }
+
+    /// Detaches the scratch registration from its agent handle
+    ///
+    /// A request dropped while in progress hands its plan to the agent, which
+    /// must not be kept alive by it. The scratch buffer stays registered until
+    /// `release` is called.
+    pub(crate) fn detach(&mut self) {
+        if let Some(handle) = self.scratch.handle.as_mut() {
+            handle.detach();
+        }
+    }
+
+    /// Deregisters a detached scratch buffer from the locked agent and frees it
+    pub(crate) fn release(self, agent: &mut AgentInner) {
+        if let Some(handle) = self.scratch.handle.as_ref() {
+            if let Err(e) = handle.deregister_from(agent) {
+                tracing::debug!(error = ?e, "Failed to deregister transform scratch buffer");
+            }
+        }
+    }
+
+    /// # Safety
+    /// `offset + len` must be within the scratch buffer, and the slice must
+    /// not be used while the backend is transferring into it
+    #[allow(clippy::mut_from_ref)]
+    unsafe fn scratch_region(&self, offset: usize, len: usize) -> &mut [u8] {
+        std::slice::from_raw_parts_mut((self.scratch.as_ptr() as *mut u8).add(offset), len)
+    }
+}
@@ -52,6 +52,12 @@ nixl_capi_create_agent(const char* name, nixl_capi_agent_t* agent)
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_create_agent_with_listener(const char* name, int port, nixl_capi_agent_t* agent)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_destroy_agent(nixl_capi_agent_t agent)
 {
@@ -70,6 +76,13 @@ nixl_capi_load_remote_md(nixl_capi_agent_t agent, const void* data, size_t len,
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_probe_remote_md(
+    nixl_capi_agent_t agent, const char* remote_name, nixl_capi_xfer_dlist_t descs, bool* all_found, size_t* missing_index)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_invalidate_remote_md(nixl_capi_agent_t agent, const char* remote_agent)
 {
@@ -82,6 +95,12 @@ nixl_capi_get_available_plugins(nixl_capi_agent_t agent, nixl_capi_string_list_t
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_preload_plugins(nixl_capi_string_list_t* plugins)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_destroy_string_list(nixl_capi_string_list_t list)
 {
@@ -210,6 +229,18 @@ nixl_capi_params_destroy_iterator(nixl_capi_param_iter_t iter)
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_create_params(nixl_capi_params_t* params)
+{
+  return nixl_capi_stub_abort();
+}
+
+nixl_capi_status_t
+nixl_capi_params_set(nixl_capi_params_t params, const char* key, const char* value)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_mem_list_is_empty(nixl_capi_mem_list_t list, bool* is_empty)
 {
@@ -255,7 +286,22 @@ nixl_capi_destroy_xfer_dlist(nixl_capi_xfer_dlist_t dlist)
 }
 
 nixl_capi_status_t
-nixl_capi_xfer_dlist_add_desc(nixl_capi_xfer_dlist_t dlist, uintptr_t addr, size_t len, uint64_t dev_id)
+nixl_capi_xfer_dlist_add_desc(nixl_capi_xfer_dlist_t dlist, uintptr_t addr, size_t len, uint64_t dev_id, size_t* index)
+{
+  return nixl_capi_stub_abort();
+}
+
+nixl_capi_status_t
+nixl_capi_xfer_dlist_add_descs(
+    nixl_capi_xfer_dlist_t dlist, const uintptr_t* addrs, const size_t* lens, const uint64_t* dev_ids,
+    size_t count)
+{
+  return nixl_capi_stub_abort();
+}
+
+nixl_capi_status_t
+nixl_capi_xfer_dlist_get_desc(
+    nixl_capi_xfer_dlist_t dlist, size_t index, uintptr_t* addr, size_t* len, uint64_t* dev_id)
 {
   return nixl_capi_stub_abort();
 }
@@ -284,6 +330,18 @@ nixl_capi_xfer_dlist_resize(nixl_capi_xfer_dlist_t dlist, size_t new_size)
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_xfer_dlist_coalesce(nixl_capi_xfer_dlist_t dlist, size_t* merged)
+{
+  return nixl_capi_stub_abort();
+}
+
+nixl_capi_status_t
+nixl_capi_xfer_dlist_sort(nixl_capi_xfer_dlist_t dlist)
+{
+  return nixl_capi_stub_abort();
+}
+
 // Registration descriptor list functions
 nixl_capi_status_t
 nixl_capi_create_reg_dlist(nixl_capi_mem_type_t mem_type, nixl_capi_reg_dlist_t* dlist, bool sorted)
@@ -303,7 +361,19 @@ nixl_capi_reg_dlist_add_desc(nixl_capi_reg_dlist_t dlist,
                              size_t len,
                              uint64_t dev_id,
                              const void *metadata,
-                             size_t metadata_len) {
+                             size_t metadata_len,
+                             size_t *index) {
+    return nixl_capi_stub_abort();
+}
+
+nixl_capi_status_t
+nixl_capi_reg_dlist_get_desc(nixl_capi_reg_dlist_t dlist,
+                             size_t index,
+                             uintptr_t *addr,
+                             size_t *len,
+                             uint64_t *dev_id,
+                             const void **metadata,
+                             size_t *metadata_len) {
     return nixl_capi_stub_abort();
 }
 
@@ -319,6 +389,12 @@ nixl_capi_reg_dlist_has_overlaps(nixl_capi_reg_dlist_t dlist, bool* has_overlaps
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_reg_dlist_sort(nixl_capi_reg_dlist_t dlist)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_reg_dlist_clear(nixl_capi_reg_dlist_t dlist)
 {
@@ -366,6 +442,13 @@ nixl_capi_get_xfer_status(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_query_xfer_backend(
+    nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl, nixl_capi_backend_t backend, bool* selected)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_destroy_xfer_req(nixl_capi_xfer_req_t req)
 {
@@ -384,6 +467,12 @@ nixl_capi_get_notifs(nixl_capi_agent_t agent, nixl_capi_notif_map_t notif_map, n
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_count_notifs(nixl_capi_agent_t agent, size_t* count)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_create_notif_map(nixl_capi_notif_map_t* notif_map)
 {
@@ -421,6 +510,25 @@ nixl_capi_notif_map_get_notif(
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_notif_map_has_agent(nixl_capi_notif_map_t map, const char* agent_name, bool* has_agent)
+{
+  return nixl_capi_stub_abort();
+}
+
+nixl_capi_status_t
+nixl_capi_notif_map_erase_agent(nixl_capi_notif_map_t map, const char* agent_name)
+{
+  return nixl_capi_stub_abort();
+}
+
+nixl_capi_status_t
+nixl_capi_notif_map_add_notif(
+    nixl_capi_notif_map_t map, const char* agent_name, const void* data, size_t len)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_notif_map_clear(nixl_capi_notif_map_t map)
 {
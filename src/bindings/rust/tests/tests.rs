@@ -21,6 +21,7 @@
 
 use nixl_sys::*;
 use std::env;
+use std::sync::Arc;
 
 // Helper function to create an agent with error handling
 fn create_test_agent(name: &str) -> Result<Agent, NixlError> {
@@ -97,6 +98,24 @@ fn test_get_available_plugins() {
     }
 }
 
+#[test]
+fn test_plugin_kinds() {
+    assert_eq!("UCX".parse::<PluginKind>().unwrap(), PluginKind::Ucx);
+    assert_eq!("GDS_MT".parse::<PluginKind>().unwrap(), PluginKind::GdsMt);
+    assert_eq!(PluginKind::Mooncake.to_string(), "Mooncake");
+    assert_eq!(
+        "Future".parse::<PluginKind>().unwrap(),
+        PluginKind::Other("Future".to_string())
+    );
+    assert_eq!(PluginKind::from("Future").to_string(), "Future");
+
+    let agent = Agent::new("plugin_kinds_agent").unwrap();
+    let kinds = agent.get_available_plugin_kinds().unwrap();
+    let names = agent.get_available_plugins().unwrap();
+    assert_eq!(kinds.len(), names.len().unwrap());
+    assert!(kinds.contains(&PluginKind::Ucx));
+}
+
 #[test]
 fn test_get_plugin_params() {
     let agent = Agent::new("test_agent").expect("Failed to create agent");
@@ -149,6 +168,34 @@ fn test_params_iteration() {
     }
 }
 
+#[test]
+fn test_params_get_typed() {
+    let agent = Agent::new("typed_params_agent").unwrap();
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+
+    assert_eq!(
+        params.get_typed("num_workers").unwrap(),
+        Some(KnownParam::NumWorkers(1))
+    );
+    assert_eq!(params.get_typed("no_such_key").unwrap(), None);
+
+    assert_eq!(
+        KnownParam::parse("device_list", "mlx5_0, mlx5_1"),
+        KnownParam::DeviceList(vec!["mlx5_0".to_string(), "mlx5_1".to_string()])
+    );
+    assert_eq!(
+        KnownParam::parse("use_aio", "true"),
+        KnownParam::UseAio(true)
+    );
+    assert_eq!(
+        KnownParam::parse("num_workers", "many"),
+        KnownParam::Other {
+            key: "num_workers".to_string(),
+            value: "many".to_string()
+        }
+    );
+}
+
 // #[test]
 // fn test_get_backend_params() {
 //     let agent = Agent::new("test_agent").unwrap();
@@ -175,6 +222,24 @@ fn test_params_iteration() {
 //     }
 // }
 
+#[test]
+fn test_agent_config() {
+    let agent = Agent::new("config_source").unwrap();
+    let (_mems, mut params) = agent.get_plugin_params("UCX").unwrap();
+    params.set(QUEUE_CAPACITY_PARAM, "8").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+
+    let config = agent.config().unwrap();
+    assert_eq!(config.backends.len(), 1);
+    assert_eq!(config.backends[0].plugin, "UCX");
+
+    let worker = Agent::new_from_config("config_worker", &config).unwrap();
+    assert_eq!(worker.name(), "config_worker");
+    let backend = worker.get_backend("UCX").unwrap();
+    assert_eq!(backend.queue_capacity().unwrap(), Some(8));
+    assert_eq!(worker.config().unwrap(), config);
+}
+
 #[test]
 fn test_get_backend_params() -> Result<(), NixlError> {
     let agent = create_test_agent("test_agent")?;
@@ -236,6 +301,293 @@ fn test_xfer_dlist() {
     assert!(dlist.has_overlaps().unwrap());
 }
 
+#[test]
+fn test_add_desc_returns_index() {
+    let mut dlist = XferDescList::new(MemType::Dram, false).unwrap();
+    assert_eq!(dlist.add_desc(0x1000, 0x100, 0).unwrap(), 0);
+    assert_eq!(dlist.add_desc(0x2000, 0x100, 0).unwrap(), 1);
+    assert_eq!(dlist.add_desc(0x3000, 0x100, 0).unwrap(), 2);
+
+    // Sorted lists report the position the descriptor was inserted at
+    let mut sorted = XferDescList::new(MemType::Dram, true).unwrap();
+    assert_eq!(sorted.add_desc(0x2000, 0x100, 0).unwrap(), 0);
+    assert_eq!(sorted.add_desc(0x3000, 0x100, 0).unwrap(), 1);
+    assert_eq!(sorted.add_desc(0x1000, 0x100, 0).unwrap(), 0);
+}
+
+#[test]
+fn test_xfer_dlist_coalesce() {
+    let mut dlist = XferDescList::new(MemType::Dram, false).unwrap();
+    dlist.add_desc(0x1000, 0x100, 0).unwrap();
+    dlist.add_desc(0x1100, 0x100, 0).unwrap();
+    dlist.add_desc(0x1200, 0x100, 0).unwrap();
+
+    assert_eq!(dlist.coalesce().unwrap(), 2);
+    assert_eq!(dlist.len().unwrap(), 1);
+
+    // Gaps and different devices are left alone
+    dlist.add_desc(0x2000, 0x100, 0).unwrap();
+    dlist.add_desc(0x2100, 0x100, 1).unwrap();
+    assert_eq!(dlist.coalesce().unwrap(), 0);
+    assert_eq!(dlist.len().unwrap(), 3);
+}
+
+#[test]
+fn test_concurrent_desc_list_builder() {
+    let builder = ConcurrentDescListBuilder::new(MemType::Dram, false);
+
+    std::thread::scope(|scope| {
+        for thread in 0..4usize {
+            let builder = &builder;
+            scope.spawn(move || {
+                for i in 0..1000usize {
+                    builder.push(0x100000 * (thread + 1) + i * 0x10, 0x10, 0);
+                }
+            });
+        }
+    });
+
+    let dlist = builder.finish().unwrap();
+    assert_eq!(dlist.len().unwrap(), 4000);
+    assert!(!dlist.has_overlaps().unwrap());
+}
+
+#[test]
+fn test_xfer_dlist_from_descs() {
+    let descs: Vec<(usize, usize, u64)> =
+        (0..1000).map(|i| (0x10000 + i * 0x100, 0x100, 0)).collect();
+    let mut dlist = XferDescList::from_descs(MemType::Dram, false, &descs).unwrap();
+    assert_eq!(dlist.len().unwrap(), 1000);
+    assert_eq!(dlist.get_desc(999).unwrap(), descs[999]);
+    assert!(!dlist.has_overlaps().unwrap());
+
+    dlist.extend_from_slice(&[(0x10080, 0x10, 0)]).unwrap();
+    assert_eq!(dlist.len().unwrap(), 1001);
+    assert!(dlist.has_overlaps().unwrap());
+
+    // Sorted lists stay sorted
+    let sorted =
+        XferDescList::from_descs(MemType::Dram, true, &[(0x3000, 0x10, 0), (0x1000, 0x10, 0)])
+            .unwrap();
+    assert_eq!(sorted.get_desc(0).unwrap(), (0x1000, 0x10, 0));
+    assert!(sorted.verify_sorted().unwrap());
+}
+
+#[test]
+fn test_xfer_dlist_template() {
+    let mut template = XferDescListTemplate::new(MemType::Dram, false);
+    template.add_desc(0, 0x100, 0);
+    template.add_desc(0x400, 0x80, 0);
+    template.add_desc(0x1000, 0x200, 1);
+    assert_eq!(template.len(), 3);
+
+    for base in [0x10000u64, 0x7f0000] {
+        let dlist = template.instantiate(base).unwrap();
+        assert_eq!(dlist.len().unwrap(), 3);
+        assert_eq!(dlist.get_desc(0).unwrap(), (base as usize, 0x100, 0));
+        assert_eq!(dlist.get_desc(1).unwrap(), (base as usize + 0x400, 0x80, 0));
+        assert_eq!(
+            dlist.get_desc(2).unwrap(),
+            (base as usize + 0x1000, 0x200, 1)
+        );
+    }
+
+    assert!(matches!(
+        template.instantiate(u64::MAX - 0x800),
+        Err(NixlError::DescriptorOverflow { index: 2 })
+    ));
+}
+
+#[test]
+fn test_xfer_dlist_get_desc() {
+    let mut dlist = XferDescList::new(MemType::Dram, false).unwrap();
+    dlist.add_desc(0x1000, 0x100, 0).unwrap();
+    dlist.add_desc(0x2000, 0x200, 1).unwrap();
+
+    assert_eq!(dlist.get_desc(1).unwrap(), (0x2000, 0x200, 1));
+    assert!(matches!(
+        dlist.get_desc(2),
+        Err(NixlError::IndexOutOfBounds)
+    ));
+}
+
+#[test]
+fn test_xfer_dlist_append_split() {
+    let mut first = XferDescList::from_descs(MemType::Dram, false, &[(0x1000, 16, 0)]).unwrap();
+    let second =
+        XferDescList::from_descs(MemType::Dram, false, &[(0x2000, 32, 0), (0x3000, 8, 1)]).unwrap();
+    first.append(&second).unwrap();
+    assert_eq!(first.len().unwrap(), 3);
+    assert_eq!(first.get_desc(1).unwrap(), (0x2000, 32, 0));
+    assert!(!first.has_overlaps().unwrap());
+
+    // Appending a descriptor that overlaps an earlier one is still detected
+    let overlapping = XferDescList::from_descs(MemType::Dram, false, &[(0x1008, 16, 0)]).unwrap();
+    first.append(&overlapping).unwrap();
+    assert!(first.has_overlaps().unwrap());
+
+    let vram = XferDescList::new(MemType::Vram, false).unwrap();
+    assert!(matches!(first.append(&vram), Err(NixlError::InvalidParam)));
+
+    let (head, tail) = first.split_at(1).unwrap();
+    assert_eq!(head.len().unwrap(), 1);
+    assert_eq!(head.get_desc(0).unwrap(), (0x1000, 16, 0));
+    assert_eq!(tail.len().unwrap(), 3);
+    assert_eq!(tail.get_desc(2).unwrap(), (0x1008, 16, 0));
+    assert_eq!(tail.get_type().unwrap(), MemType::Dram);
+
+    let (all, none) = first.split_at(4).unwrap();
+    assert_eq!((all.len().unwrap(), none.len().unwrap()), (4, 0));
+    assert!(matches!(
+        first.split_at(5),
+        Err(NixlError::IndexOutOfBounds)
+    ));
+
+    // Sorted lists stay sorted when appended to
+    let mut sorted = XferDescList::from_descs(MemType::Dram, true, &[(0x3000, 8, 0)]).unwrap();
+    sorted.append(&second).unwrap();
+    assert!(sorted.verify_sorted().unwrap());
+}
+
+#[test]
+fn test_dlist_iter() {
+    let storage = SystemStorage::new(1024).unwrap();
+    let mut xfer = XferDescList::new(MemType::Dram, false).unwrap();
+    xfer.add_storage_desc(&storage).unwrap();
+    xfer.add_desc(0x2000, 0x200, 1).unwrap();
+
+    let descs: Vec<Desc> = xfer.iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(descs.len(), 2);
+    assert_eq!(descs[0].addr, storage.as_slice().as_ptr() as usize);
+    assert_eq!(descs[0].len, 1024);
+    assert_eq!(descs[0].meta, None);
+    assert_eq!(xfer.get(1).unwrap(), descs[1]);
+    assert!(matches!(xfer.get(2), Err(NixlError::IndexOutOfBounds)));
+
+    let mut reg = RegDescList::new(MemType::Dram, false).unwrap();
+    reg.add_desc_with_meta(0x1000, 0x100, 0, b"meta").unwrap();
+    reg.add_desc(0x3000, 0x100, 0).unwrap();
+    let mut count = 0;
+    for desc in &reg {
+        let desc = desc.unwrap();
+        let expected: &[u8] = if count == 0 { b"meta" } else { b"" };
+        assert_eq!(desc.meta.as_deref(), Some(expected));
+        count += 1;
+    }
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_xfer_dlist_find_covering() {
+    let mut dlist = XferDescList::new(MemType::Dram, false).unwrap();
+    for i in (0..100).rev() {
+        dlist.add_desc(0x10000 + i * 0x100, 0x80, 0).unwrap();
+    }
+    assert_eq!(dlist.find_covering(0x10000 + 42 * 0x100).unwrap(), Some(57));
+
+    dlist.sort_by_addr().unwrap();
+    assert!(dlist.is_sorted().unwrap());
+    assert!(dlist.verify_sorted().unwrap());
+
+    assert_eq!(
+        dlist.find_covering(0x10000 + 42 * 0x100 + 0x7f).unwrap(),
+        Some(42)
+    );
+    assert_eq!(dlist.find_covering(0x10000).unwrap(), Some(0));
+    // Gaps between descriptors and addresses outside the list are not covered
+    assert_eq!(
+        dlist.find_covering(0x10000 + 42 * 0x100 + 0x80).unwrap(),
+        None
+    );
+    assert_eq!(dlist.find_covering(0xffff).unwrap(), None);
+    assert_eq!(dlist.find_covering(0x10000 + 100 * 0x100).unwrap(), None);
+}
+
+#[test]
+fn test_xfer_dlist_fingerprint() {
+    let build = |descs: &[(usize, usize)]| {
+        let mut dlist = XferDescList::new(MemType::Dram, false).unwrap();
+        for &(addr, len) in descs {
+            dlist.add_desc(addr, len, 0).unwrap();
+        }
+        dlist
+    };
+
+    let a = build(&[(0x1000, 0x100), (0x2000, 0x200)]);
+    let b = build(&[(0x1000, 0x100), (0x2000, 0x200)]);
+    let reordered = build(&[(0x2000, 0x200), (0x1000, 0x100)]);
+
+    assert_eq!(a.fingerprint().unwrap(), b.fingerprint().unwrap());
+    assert_ne!(a.fingerprint().unwrap(), reordered.fingerprint().unwrap());
+
+    let mut vram = XferDescList::new(MemType::Vram, false).unwrap();
+    vram.add_desc(0x1000, 0x100, 0).unwrap();
+    vram.add_desc(0x2000, 0x200, 0).unwrap();
+    assert_ne!(a.fingerprint().unwrap(), vram.fingerprint().unwrap());
+}
+
+#[test]
+fn test_xfer_dlist_diff_report() {
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_desc(0x1000, 0x100, 0).unwrap();
+    local.add_desc(0x2000, 0x200, 0).unwrap();
+
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_desc(0x5000, 0x100, 0).unwrap();
+    remote.add_desc(0x6000, 0x100, 0).unwrap();
+    remote.add_desc(0x7000, 0x100, 0).unwrap();
+
+    let report = local.diff_report(&remote).unwrap();
+    assert!(report.contains("total length: 768 | 768\n"));
+    assert!(report.contains("descriptors: 2 | 3  <- mismatch"));
+    assert!(report.contains("[1] 0x2000 len 512 dev 0 | 0x6000 len 256 dev 0  <- length mismatch"));
+    assert!(report.contains("[2] - | 0x7000 len 256 dev 0  <- missing"));
+
+    remote.rem_desc(2).unwrap();
+    remote.rem_desc(1).unwrap();
+    let report = local.diff_report(&remote).unwrap();
+    assert!(report.contains("total length: 768 | 256  <- mismatch"));
+    assert!(!local.diff_report(&local).unwrap().contains("<-"));
+}
+
+#[test]
+fn test_xfer_dlist_validate() {
+    let mut dlist = XferDescList::new(MemType::Dram, false).unwrap();
+    dlist.add_desc(0x1000, 0x100, 0).unwrap();
+    dlist.add_desc(0x1000, 0x100, 1).unwrap();
+    dlist.validate().unwrap();
+
+    // addr + len runs exactly past the top of the address space
+    dlist.add_desc(usize::MAX - 0xf, 0x10, 0).unwrap();
+    assert!(matches!(
+        dlist.validate(),
+        Err(NixlError::DescriptorOverflow { index: 2 })
+    ));
+
+    let mut wrapping = XferDescList::new(MemType::Dram, false).unwrap();
+    wrapping.add_desc(usize::MAX, 0x1000, 0).unwrap();
+    assert!(matches!(
+        wrapping.validate(),
+        Err(NixlError::DescriptorOverflow { index: 0 })
+    ));
+
+    let mut zero = XferDescList::new(MemType::Dram, false).unwrap();
+    zero.add_desc(0x1000, 0, 0).unwrap();
+    assert!(matches!(
+        zero.validate(),
+        Err(NixlError::ZeroLengthDescriptor { index: 0 })
+    ));
+
+    let mut duplicate = XferDescList::new(MemType::Dram, false).unwrap();
+    duplicate.add_desc(0x1000, 0x100, 0).unwrap();
+    duplicate.add_desc(0x2000, 0x100, 0).unwrap();
+    duplicate.add_desc(0x1000, 0x100, 0).unwrap();
+    assert!(matches!(
+        duplicate.validate(),
+        Err(NixlError::DuplicateDescriptor { index: 2 })
+    ));
+}
+
 #[test]
 fn test_reg_dlist() {
     let mut dlist = RegDescList::new(MemType::Dram, false).unwrap();
@@ -306,6 +658,292 @@ fn test_memory_registration() {
     assert!(storage.as_slice().iter().all(|&x| x == 0xAA));
 }
 
+#[test]
+fn test_storage_fill_with() {
+    let agent = Agent::new("fill_with_agent").unwrap();
+    let mut storage = SystemStorage::new(1024).unwrap();
+    storage.register(&agent, None).unwrap();
+
+    storage.fill_with(|i| (i % 256) as u8);
+    assert!(storage
+        .as_slice()
+        .iter()
+        .enumerate()
+        .all(|(i, &x)| x == (i % 256) as u8));
+
+    let mut dlist = XferDescList::new(MemType::Dram, false).unwrap();
+    dlist.add_storage_desc(&storage).unwrap();
+    assert_eq!(dlist.desc_count().unwrap(), 1);
+}
+
+#[test]
+fn test_dev_dax_storage() {
+    let path = std::path::Path::new("/dev/dax0.0");
+    if !path.exists() {
+        println!("No device DAX present, skipping test");
+        return;
+    }
+
+    assert!(matches!(
+        DevDaxStorage::open(path, DEV_DAX_ALIGNMENT + 1),
+        Err(NixlError::InvalidParam)
+    ));
+
+    let agent = Agent::new("dev_dax_agent").unwrap();
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+
+    let mut storage = DevDaxStorage::open(path, DEV_DAX_ALIGNMENT).unwrap();
+    storage.register(&agent, None).unwrap();
+
+    for (i, byte) in storage.as_mut_slice().iter_mut().enumerate() {
+        *byte = (i % 256) as u8;
+    }
+    assert!(storage
+        .as_slice()
+        .iter()
+        .enumerate()
+        .all(|(i, &x)| x == (i % 256) as u8));
+}
+
+#[cfg(feature = "cuda")]
+#[test]
+fn test_cuda_storage() {
+    let mut storage = match CudaStorage::new(4096, 0) {
+        Ok(storage) => storage,
+        Err(NixlError::CudaError(code)) => {
+            println!("No usable CUDA device (error {code}), skipping test");
+            return;
+        }
+        Err(e) => panic!("Failed to allocate CUDA storage: {e}"),
+    };
+    assert_eq!(storage.mem_type(), MemType::Vram);
+    assert_eq!(storage.device_id(), 0);
+
+    let mut host = vec![0u8; 4096];
+    storage.memset(0xab).unwrap();
+    storage.copy_to_host(&mut host).unwrap();
+    assert!(host.iter().all(|&x| x == 0xab));
+
+    let pattern: Vec<u8> = (0..4096).map(|i| (i % 251) as u8).collect();
+    storage.copy_from_host(&pattern).unwrap();
+    storage.copy_to_host(&mut host).unwrap();
+    assert_eq!(host, pattern);
+    assert!(storage.copy_from_host(&[0; 4097]).is_err());
+
+    let agent = Agent::new("cuda_storage_agent").unwrap();
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+    storage.register(&agent, None).unwrap();
+
+    let mut dlist = XferDescList::new(MemType::Vram, false).unwrap();
+    dlist.add_storage_desc(&storage).unwrap();
+    assert_eq!(dlist.len().unwrap(), 1);
+    drop(dlist);
+
+    // Dropping the storage deregisters it, so a new allocation registers cleanly
+    drop(storage);
+    let mut new_storage = CudaStorage::new(4096, 0).unwrap();
+    new_storage.register(&agent, None).unwrap();
+}
+
+#[cfg(feature = "cuda")]
+extern "C" {
+    fn cuCtxGetCurrent(ctx: *mut *mut std::ffi::c_void) -> i32;
+    fn cuCtxSetCurrent(ctx: *mut std::ffi::c_void) -> i32;
+}
+
+#[cfg(feature = "cuda")]
+#[test]
+fn test_cuda_storage_wrong_context() {
+    let mut storage = match CudaStorage::new(4096, 0) {
+        Ok(storage) => storage,
+        Err(NixlError::CudaError(code)) => {
+            println!("No usable CUDA device (error {code}), skipping test");
+            return;
+        }
+        Err(e) => panic!("Failed to allocate CUDA storage: {e}"),
+    };
+    let agent = Agent::new("cuda_context_agent").unwrap();
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+    storage.register(&agent, None).unwrap();
+
+    // Deregistering with no context current is refused and keeps the registration
+    let mut context = std::ptr::null_mut();
+    assert_eq!(unsafe { cuCtxGetCurrent(&mut context) }, 0);
+    assert_eq!(unsafe { cuCtxSetCurrent(std::ptr::null_mut()) }, 0);
+    assert!(matches!(
+        storage.deregister(),
+        Err(NixlError::WrongCudaContext)
+    ));
+    let addr = unsafe { storage.as_ptr() } as usize;
+    assert!(agent.region_info(addr).is_some());
+
+    assert_eq!(unsafe { cuCtxSetCurrent(context) }, 0);
+    storage.deregister().unwrap();
+    assert!(agent.region_info(addr).is_none());
+}
+
+#[cfg(feature = "cuda")]
+#[test]
+fn test_cuda_register_tensor() {
+    let storage = match CudaStorage::new(128, 0) {
+        Ok(storage) => storage,
+        Err(NixlError::CudaError(code)) => {
+            println!("No usable CUDA device (error {code}), skipping test");
+            return;
+        }
+        Err(e) => panic!("Failed to allocate CUDA storage: {e}"),
+    };
+    let agent = Agent::new("cuda_tensor_agent").unwrap();
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+    let base = unsafe { storage.as_ptr() };
+
+    // Mismatched or empty layouts are rejected before registering
+    let invalid = unsafe { CudaStorage::register_tensor(&agent, base, &[4], &[8, 1], 4, 0, None) };
+    assert!(matches!(invalid, Err(NixlError::InvalidParam)));
+    let invalid = unsafe { CudaStorage::register_tensor(&agent, base, &[], &[], 4, 0, None) };
+    assert!(matches!(invalid, Err(NixlError::InvalidParam)));
+
+    // A 4x6 slice of f32 with rows padded to 8 elements
+    let tensor =
+        unsafe { CudaStorage::register_tensor(&agent, base, &[4, 6], &[8, 1], 4, 0, None) }
+            .unwrap();
+    assert_eq!(tensor.size(), (3 * 8 + 6) * 4);
+    assert_eq!(tensor.mem_type(), MemType::Vram);
+
+    let mut dlist = XferDescList::new(MemType::Vram, false).unwrap();
+    assert_eq!(tensor.add_view_descs(&mut dlist, &[2]).unwrap(), 1);
+    assert_eq!(tensor.add_view_descs(&mut dlist, &[]).unwrap(), 4);
+    assert_eq!(tensor.add_view_descs(&mut dlist, &[1, 3]).unwrap(), 1);
+    assert!(matches!(
+        tensor.add_view_descs(&mut dlist, &[4]),
+        Err(NixlError::IndexOutOfBounds)
+    ));
+    assert_eq!(dlist.len().unwrap(), 6);
+    let (addr, len, _) = dlist.get_desc(0).unwrap();
+    assert_eq!(addr, base as usize + 2 * 8 * 4);
+    assert_eq!(len, 6 * 4);
+    drop(dlist);
+    drop(tensor);
+
+    // The transposed view has no contiguous rows, so each element is a descriptor
+    let transposed =
+        unsafe { CudaStorage::register_tensor(&agent, base, &[6, 4], &[1, 8], 4, 0, None) }
+            .unwrap();
+    let mut dlist = XferDescList::new(MemType::Vram, false).unwrap();
+    assert_eq!(transposed.add_view_descs(&mut dlist, &[0]).unwrap(), 4);
+    assert_eq!(dlist.get_desc(1).unwrap().0, base as usize + 8 * 4);
+}
+
+#[cfg(feature = "cuda")]
+#[test]
+fn test_cuda_storage_pool() {
+    let agent = Agent::new("cuda_pool_agent").unwrap();
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+
+    let pool = match CudaStoragePool::new(&agent, 1024, 3, 0) {
+        Ok(pool) => pool,
+        Err(NixlError::CudaError(code)) => {
+            println!("No usable CUDA device (error {code}), skipping test");
+            return;
+        }
+        Err(e) => panic!("Failed to create CUDA storage pool: {e}"),
+    };
+    assert_eq!(pool.capacity(), 3);
+    assert_eq!(pool.buffer_size(), 1024);
+    assert!(matches!(
+        CudaStoragePool::new(&agent, 1024, 0, 0),
+        Err(NixlError::InvalidParam)
+    ));
+
+    let mut buffers: Vec<_> = (0..3).map(|_| pool.checkout().unwrap()).collect();
+    assert_eq!(pool.available(), 0);
+    assert!(pool.checkout().is_none());
+
+    // Checked out buffers are registered device memory
+    buffers[0].memset(0x3c).unwrap();
+    let mut host = vec![0u8; 1024];
+    buffers[0].copy_to_host(&mut host).unwrap();
+    assert!(host.iter().all(|&x| x == 0x3c));
+    let mut dlist = XferDescList::new(MemType::Vram, false).unwrap();
+    for buffer in &buffers {
+        assert_eq!(buffer.size(), 1024);
+        dlist.add_storage_desc(&**buffer).unwrap();
+    }
+    assert_eq!(dlist.len().unwrap(), 3);
+    drop(dlist);
+
+    buffers.pop();
+    assert_eq!(pool.available(), 1);
+    drop(buffers);
+    assert_eq!(pool.available(), 3);
+    assert!(pool.checkout().is_some());
+    assert_eq!(pool.available(), 3);
+}
+
+#[cfg(feature = "cuda")]
+#[test]
+fn test_gds_storage() {
+    const SIZE: usize = 4096;
+
+    let agent = Agent::new("gds_agent").unwrap();
+    let plugins = agent.get_available_plugins().unwrap();
+    if !plugins
+        .iter()
+        .any(|p| p.map(|s| s == "GDS").unwrap_or(false))
+    {
+        println!("GDS plugin not available, skipping test");
+        return;
+    }
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("gds_storage.bin");
+    let mut storage = match GdsStorage::open(&path, SIZE, 0) {
+        Ok(storage) => storage,
+        Err(NixlError::CudaError(code)) => {
+            println!("No usable CUDA device (error {code}), skipping test");
+            return;
+        }
+        Err(e) => panic!("Failed to open GDS storage: {e}"),
+    };
+    assert_eq!(std::fs::metadata(&path).unwrap().len(), SIZE as u64);
+
+    let (_mems, params) = agent.get_plugin_params("GDS").unwrap();
+    let backend = agent.create_backend("GDS", &params).unwrap();
+    let mut opt_args = OptArgs::new().unwrap();
+    opt_args.add_backend(&backend).unwrap();
+
+    storage.buffer_mut().memset(0x5a).unwrap();
+    match storage.register(&agent, Some(&opt_args)) {
+        Ok(()) => {}
+        Err(NixlError::GdsError(message)) => {
+            println!("GDS unusable on this machine ({message}), skipping test");
+            return;
+        }
+        Err(e) => panic!("Failed to register GDS storage: {e}"),
+    }
+
+    let mut reg_descs = RegDescList::new(MemType::Vram, false).unwrap();
+    reg_descs.add_storage_desc(&storage).unwrap();
+    assert_eq!(reg_descs.len().unwrap(), 1);
+
+    let mut local = XferDescList::new(MemType::Vram, false).unwrap();
+    local.add_storage_desc(&storage).unwrap();
+    let mut remote = XferDescList::new(MemType::File, false).unwrap();
+    storage.add_file_desc(&mut remote).unwrap();
+    let (_addr, len, dev_id) = remote.get_desc(0).unwrap();
+    assert_eq!((len, dev_id), (SIZE, storage.fd() as u64));
+
+    agent
+        .transfer(XferOp::Write, &local, &remote, &agent.name(), None)
+        .unwrap();
+    assert!(std::fs::read(&path).unwrap().iter().all(|&b| b == 0x5a));
+}
+
 #[test]
 fn test_registration_handle_drop() {
     let agent = Agent::new("test_agent").unwrap();
@@ -322,6 +960,148 @@ fn test_registration_handle_drop() {
     new_storage.register(&agent, None).unwrap();
 }
 
+#[test]
+fn test_storage_deregister() {
+    let agent1 = Agent::new("deregister_A1").unwrap();
+    let agent2 = Agent::new("deregister_A2").unwrap();
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+    let mut storage = SystemStorage::new(1024).unwrap();
+    let addr = storage.as_slice().as_ptr() as usize;
+
+    // Not registered yet
+    storage.deregister(&agent1).unwrap();
+
+    storage.register(&agent1, None).unwrap();
+    assert!(matches!(
+        storage.deregister(&agent2),
+        Err(NixlError::InvalidParam)
+    ));
+    assert!(agent1.region_info(addr).is_some());
+
+    storage.deregister(&agent1).unwrap();
+    storage.deregister(&agent1).unwrap();
+    assert!(agent1.region_info(addr).is_none());
+    assert!(storage.region_info().is_none());
+
+    storage.memset(0x7e);
+    assert!(storage.as_slice().iter().all(|&x| x == 0x7e));
+
+    storage.register(&agent2, None).unwrap();
+    assert!(agent2.region_info(addr).is_some());
+    assert_eq!(storage.region_info().map(|info| info.addr()), Some(addr));
+}
+
+#[test]
+fn test_register_scoped() {
+    let agent1 = Agent::new("scoped_A1").unwrap();
+    let agent2 = Agent::new("scoped_A2").unwrap();
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+    let storage = SystemStorage::new(1024).unwrap();
+    let addr = storage.as_slice().as_ptr() as usize;
+
+    let reg1 = storage.register_scoped(&agent1, None).unwrap();
+    let reg2 = agent2.register_scoped(&storage, None).unwrap();
+    assert_eq!(reg1.info().addr(), addr);
+    assert!(agent1.region_info(addr).is_some());
+    assert!(agent2.region_info(addr).is_some());
+
+    // Each registration is dropped independently of the other and of the storage
+    drop(reg1);
+    assert!(agent1.region_info(addr).is_none());
+    assert!(agent2.region_info(addr).is_some());
+
+    reg2.deregister().unwrap();
+    assert!(agent2.region_info(addr).is_none());
+    assert!(storage.region_info().is_none());
+}
+
+#[test]
+fn test_registration_overhead() {
+    let agent = Agent::new("overhead_agent").unwrap();
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+    assert_eq!(
+        agent.registration_overhead().unwrap(),
+        RegistrationStats::default()
+    );
+
+    let mut buffers = vec![
+        SystemStorage::new(1024).unwrap(),
+        SystemStorage::new(4096).unwrap(),
+        SystemStorage::new(256).unwrap(),
+    ];
+    for buffer in buffers.iter_mut() {
+        buffer.register(&agent, None).unwrap();
+    }
+
+    let stats = agent.registration_overhead().unwrap();
+    assert_eq!(stats.total_bytes(), 1024 + 4096 + 256);
+    assert_eq!(stats.count(), 3);
+    assert_eq!(stats.backend_registrations(), 3);
+
+    // Deregistered buffers no longer count
+    buffers.pop();
+    let stats = agent.registration_overhead().unwrap();
+    assert_eq!(stats.total_bytes(), 1024 + 4096);
+    assert_eq!(stats.count(), 2);
+}
+
+#[test]
+fn test_leak_registration() {
+    let agent = Agent::new("leak_agent").unwrap();
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+    let empty_md = agent.get_local_md().unwrap();
+
+    let mut storage = SystemStorage::new(1024).unwrap();
+    storage.register(&agent, None).unwrap();
+    let registered_md = agent.get_local_md().unwrap();
+    assert_ne!(registered_md, empty_md);
+
+    storage.leak_registration();
+    drop(storage);
+
+    // The region is still part of the agent's metadata
+    assert_eq!(agent.get_local_md().unwrap(), registered_md);
+}
+
+#[test]
+fn test_register_with_context() {
+    let agent = Agent::new("context_agent").unwrap();
+    let mut storage = SystemStorage::new(1024).unwrap();
+    assert!(storage.region_info().is_none());
+
+    // Without a backend NIXL rejects the registration, so no region is recorded
+    let mut unbacked = SystemStorage::new(64).unwrap();
+    assert!(unbacked.register_with_context(&agent, 7, None).is_err());
+    assert!(unbacked.region_info().is_none());
+    assert_eq!(
+        agent.region_info(unsafe { unbacked.as_ptr() } as usize),
+        None
+    );
+
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+
+    storage.register_with_context(&agent, 42, None).unwrap();
+    let info = storage.region_info().unwrap();
+    assert_eq!(info.context(), 42);
+    assert_eq!(info.size(), 1024);
+    assert_eq!(info.mem_type(), MemType::Dram);
+
+    // The agent finds the region from any address inside it
+    let addr = unsafe { storage.as_ptr() } as usize;
+    assert_eq!(agent.region_info(addr + 512), Some(info));
+    assert_eq!(agent.region_info(addr + 1024), None);
+
+    drop(storage);
+    assert_eq!(agent.region_info(addr), None);
+}
+
 #[test]
 fn test_multiple_registrations() {
     let agent = Agent::new("test_agent").unwrap();
@@ -409,6 +1189,27 @@ fn test_get_local_md() {
     assert!(final_size > initial_size);
 }
 
+#[test]
+fn test_get_local_md_into() {
+    let agent = Agent::new("md_into_agent").unwrap();
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+
+    let mut buf = vec![0xffu8; 4];
+    agent.get_local_md_into(&mut buf).unwrap();
+    assert_eq!(buf, agent.get_local_md().unwrap());
+
+    let mut storage = SystemStorage::new(1024).unwrap();
+    storage.register(&agent, None).unwrap();
+
+    agent.get_local_md_into(&mut buf).unwrap();
+    assert_eq!(buf, agent.get_local_md().unwrap());
+
+    let peer_agent = Agent::new("md_into_peer").unwrap();
+    let _peer_backend = peer_agent.create_backend("UCX", &params).unwrap();
+    assert_eq!(peer_agent.load_remote_peer(&buf).unwrap().region_count, 1);
+}
+
 #[test]
 fn test_metadata_exchange() {
     // Create two agents
@@ -431,33 +1232,471 @@ fn test_metadata_exchange() {
 }
 
 #[test]
-fn test_basic_agent_lifecycle() {
-    // Create two agents
-    let agent2 = Agent::new("A2").unwrap();
-    let agent1 = Agent::new("A1").unwrap();
+fn test_metadata_bytes_round_trip() {
+    let agent1 = Agent::new("md_bytes_A1").unwrap();
+    let agent2 = Agent::new("md_bytes_A2").unwrap();
 
-    // Get available plugins and print their names
-    let plugins = agent1.get_available_plugins().unwrap();
-    for plugin in plugins.iter() {
-        println!("Found plugin: {}", plugin.unwrap());
-    }
+    let (_mem_list, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
 
-    // Get plugin parameters for both agents
-    let (_mem_list1, _params) = agent1.get_plugin_params("UCX").unwrap();
-    let (_mem_list2, params) = agent2.get_plugin_params("UCX").unwrap();
+    let md = agent1.local_metadata().unwrap();
+    let wire = md.as_bytes().to_vec();
+    let received = Metadata::from_bytes(&wire).unwrap();
+    assert_eq!(received, md);
 
-    // Create backends for both agents
-    let backend1 = agent1.create_backend("UCX", &params).unwrap();
-    let backend2 = agent2.create_backend("UCX", &params).unwrap();
+    assert_eq!(
+        agent2.load_remote_md(received.as_bytes()).unwrap(),
+        "md_bytes_A1"
+    );
+    assert!(Metadata::from_bytes(b"not metadata").is_err());
+}
 
-    // Create optional arguments and add backends
-    let mut opt_args = OptArgs::new().unwrap();
-    opt_args.add_backend(&backend1).unwrap();
-    opt_args.add_backend(&backend2).unwrap();
+#[cfg(feature = "serde")]
+#[test]
+fn test_metadata_serde() {
+    use serde::Deserialize;
 
-    // Allocate and initialize memory regions
-    let mut storage1 = SystemStorage::new(256).unwrap();
-    let mut storage2 = SystemStorage::new(256).unwrap();
+    let agent = Agent::new("md_serde_agent").unwrap();
+    let (_mem_list, params) = agent.get_plugin_params("UCX").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+    let md = agent.local_metadata().unwrap();
+
+    // Deserializing goes through the same check as from_bytes
+    let deserializer = serde::de::value::SeqDeserializer::<_, serde::de::value::Error>::new(
+        md.as_bytes().to_vec().into_iter(),
+    );
+    assert_eq!(Metadata::deserialize(deserializer).unwrap(), md);
+
+    let deserializer = serde::de::value::SeqDeserializer::<_, serde::de::value::Error>::new(
+        b"not metadata".to_vec().into_iter(),
+    );
+    assert!(Metadata::deserialize(deserializer).is_err());
+}
+
+#[test]
+fn test_remote_peers() {
+    let agent1 = Agent::new("remote_peers_A1").unwrap();
+    let agent2 = Agent::new("remote_peers_A2").unwrap();
+    let agent3 = Agent::new("remote_peers_A3").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+    let _backend3 = agent3.create_backend("UCX", &params).unwrap();
+
+    assert!(agent1.remote_peers().unwrap().is_empty());
+
+    agent1
+        .load_remote_md(&agent3.get_local_md().unwrap())
+        .unwrap();
+    agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    assert_eq!(
+        agent1.remote_peers().unwrap(),
+        vec!["remote_peers_A2".to_string(), "remote_peers_A3".to_string()]
+    );
+
+    agent1.invalidate_remote_md("remote_peers_A2").unwrap();
+    assert_eq!(
+        agent1.remote_peers().unwrap(),
+        vec!["remote_peers_A3".to_string()]
+    );
+}
+
+#[test]
+fn test_invalidate_remote_md() {
+    let agent1 = Agent::new("invalidate_md_A1").unwrap();
+    let agent2 = Agent::new("invalidate_md_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let metadata = agent2.get_local_md().unwrap();
+    let remote_name = agent1.load_remote_md(&metadata).unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+
+    agent1.invalidate_remote_md(&remote_name).unwrap();
+    let result = agent1.create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None);
+    assert!(matches!(result, Err(NixlError::RemoteInvalidated(name)) if name == remote_name));
+    assert!(matches!(
+        agent1.invalidate_remote_md(&remote_name),
+        Err(NixlError::InvalidParam)
+    ));
+
+    // Loading the metadata again makes the remote usable
+    agent1.load_remote_md(&metadata).unwrap();
+    agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+}
+
+#[test]
+fn test_prep_xfer() {
+    let agent1 = Agent::new("prep_xfer_A1").unwrap();
+    let agent2 = Agent::new("prep_xfer_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+    let mut opt_args = OptArgs::new().unwrap();
+    opt_args.add_backend(&backend1).unwrap();
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    agent1.prep_xfer(&remote_name, Some(&opt_args)).unwrap();
+    agent1.prep_xfer(&remote_name, None).unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+    while agent1.get_xfer_status(&req).unwrap() {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+
+    agent1.invalidate_remote_md(&remote_name).unwrap();
+    assert!(matches!(
+        agent1.prep_xfer(&remote_name, None),
+        Err(NixlError::RemoteInvalidated(name)) if name == remote_name
+    ));
+}
+
+#[test]
+fn test_agent_shutdown() {
+    let agent1 = Agent::new("shutdown_A1").unwrap();
+    let agent2 = Agent::new("shutdown_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(1 << 20).unwrap();
+    let mut storage2 = SystemStorage::new(1 << 20).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+    agent1.clone().shutdown().unwrap();
+    assert!(!agent1.get_xfer_status(&req).unwrap());
+    let result = agent1.create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None);
+    assert!(matches!(result, Err(NixlError::RemoteInvalidated(_))));
+
+    // A bounded shutdown behaves the same when transfers finish in time
+    agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+    agent1
+        .clone()
+        .shutdown_timeout(std::time::Duration::from_secs(30))
+        .unwrap();
+    assert!(!agent1.get_xfer_status(&req).unwrap());
+
+    // Dropping the agent with a transfer in flight must not crash
+    agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+    drop(agent1);
+    drop(req);
+}
+
+#[test]
+fn test_load_remote_peer() {
+    let agent1 = Agent::new("remote_peer_A1").unwrap();
+    let agent2 = Agent::new("remote_peer_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(512).unwrap();
+    storage1.register(&agent2, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let peer = agent1
+        .load_remote_peer(&agent2.get_local_md().unwrap())
+        .unwrap();
+    assert_eq!(peer.name, "remote_peer_A2");
+    assert_eq!(peer.mem_types, vec![MemType::Dram]);
+    assert_eq!(peer.backends, vec!["UCX".to_string()]);
+    assert_eq!(peer.region_count, 2);
+    assert!(agent1.check_remote_metadata(&peer.name, None));
+
+    assert!(agent1.load_remote_peer(b"not metadata").is_err());
+}
+
+#[test]
+fn test_refresh_remote_md() {
+    let agent1 = Agent::new("refresh_md_A1").unwrap();
+    let agent2 = Agent::new("refresh_md_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut old_storage = SystemStorage::new(256).unwrap();
+    let mut new_storage = SystemStorage::new(256).unwrap();
+    storage1.memset(0x3c);
+    storage1.register(&agent1, None).unwrap();
+    old_storage.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    new_storage.register(&agent2, None).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&new_storage).unwrap();
+    assert!(!agent1.check_remote_metadata(&remote_name, Some(&remote)));
+
+    agent1
+        .refresh_remote_md(&remote_name, &agent2.get_local_md().unwrap())
+        .unwrap();
+    assert!(agent1.check_remote_metadata(&remote_name, Some(&remote)));
+    assert!(agent1.refresh_remote_md("unknown_agent", &[]).is_err());
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+    while agent1.get_xfer_status(&req).unwrap() {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    assert!(new_storage.as_slice().iter().all(|&x| x == 0x3c));
+}
+
+#[test]
+fn test_metadata_file_exchange() {
+    let agent1 = Agent::new("md_file_A1").unwrap();
+    let agent2 = Agent::new("md_file_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let temp_dir = tempfile::tempdir().expect("Failed to create temporary directory");
+    let md_path = temp_dir.path().join("md_file_A1.md");
+
+    agent1.dump_local_md_to_file(&md_path).unwrap();
+    assert_eq!(
+        agent2.load_remote_md_from_file(&md_path).unwrap(),
+        "md_file_A1"
+    );
+    assert!(agent2.check_remote_metadata("md_file_A1", None));
+
+    let missing = temp_dir.path().join("missing.md");
+    assert!(matches!(
+        agent2.load_remote_md_from_file(&missing),
+        Err(NixlError::Io(std::io::ErrorKind::NotFound))
+    ));
+}
+
+#[test]
+fn test_xfer_state() {
+    let agent1 = Agent::new("xfer_state_A1").unwrap();
+    let agent2 = Agent::new("xfer_state_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(1024).unwrap();
+    let mut storage2 = SystemStorage::new(1024).unwrap();
+    storage1.memset(0x33);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+    assert_eq!(agent1.xfer_state(&req).unwrap(), XferState::NotPosted);
+
+    agent1.post_xfer_req(&req, None).unwrap();
+    let start = std::time::Instant::now();
+    loop {
+        match agent1.xfer_state(&req).unwrap() {
+            XferState::InProgress => {
+                assert!(
+                    start.elapsed().as_secs() < 5,
+                    "Timed out waiting for transfer"
+                );
+                std::thread::yield_now();
+            }
+            state => {
+                assert_eq!(state, XferState::Done);
+                break;
+            }
+        }
+    }
+    assert!(!agent1.get_xfer_status(&req).unwrap());
+    assert!(storage2.as_slice().iter().all(|&b| b == 0x33));
+}
+
+#[test]
+fn test_debug_output() {
+    let agent1 = Agent::new("debug_A1").unwrap();
+    let agent2 = Agent::new("debug_A2").unwrap();
+    assert_eq!(format!("{agent1:?}"), r#"Agent { name: "debug_A1" }"#);
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let backend = agent1.create_backend("UCX", &params).unwrap();
+    agent2.create_backend("UCX", &params).unwrap();
+
+    let mut args = OptArgs::new().unwrap();
+    args.add_backend(&backend).unwrap();
+    args.set_has_notification(true).unwrap();
+    let debug = format!("{args:?}");
+    assert!(debug.starts_with("OptArgs { backends: 1, has_notification: true"));
+
+    let mut storage1 = SystemStorage::new(64).unwrap();
+    let mut storage2 = SystemStorage::new(64).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, true).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    assert_eq!(
+        format!("{local:?}"),
+        "XferDescList { mem_type: Dram, len: 1, sorted: true }"
+    );
+    let reg = RegDescList::new(MemType::File, false).unwrap();
+    assert_eq!(
+        format!("{reg:?}"),
+        "RegDescList { mem_type: File, len: 0, sorted: false }"
+    );
+
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+    assert_eq!(
+        format!("{req:?}"),
+        r#"XferRequest { operation: Write, remote_agent: "debug_A2", descriptors: 1, bytes: 64 }"#
+    );
+
+    // Raw pointers are never printed
+    for debug in [
+        format!("{agent1:?}"),
+        format!("{args:?}"),
+        format!("{req:?}"),
+    ] {
+        assert!(!debug.contains("0x"));
+    }
+}
+
+#[test]
+fn test_notification_message_limit() {
+    let mut args = OptArgs::new().unwrap();
+    assert_eq!(args.notification_message(), None);
+    assert_eq!(args.max_notification_len(), MAX_NOTIFICATION_LEN);
+
+    args.set_notification_message(b"notification").unwrap();
+    assert_eq!(args.notification_message(), Some(&b"notification"[..]));
+    assert_eq!(args.get_notification_message().unwrap(), b"notification");
+
+    let largest = vec![0x42; MAX_NOTIFICATION_LEN];
+    args.set_notification_message(&largest).unwrap();
+    assert_eq!(args.notification_message(), Some(&largest[..]));
+
+    // An oversized message is rejected and the previous one is kept
+    let oversized = vec![0x42; MAX_NOTIFICATION_LEN + 1];
+    assert!(matches!(
+        args.set_notification_message(&oversized),
+        Err(NixlError::NotificationTooLarge { len, max })
+            if len == MAX_NOTIFICATION_LEN + 1 && max == MAX_NOTIFICATION_LEN
+    ));
+    assert_eq!(args.get_notification_message().unwrap(), largest);
+}
+
+#[test]
+fn test_basic_agent_lifecycle() {
+    // Create two agents
+    let agent2 = Agent::new("A2").unwrap();
+    let agent1 = Agent::new("A1").unwrap();
+
+    // Get available plugins and print their names
+    let plugins = agent1.get_available_plugins().unwrap();
+    for plugin in plugins.iter() {
+        println!("Found plugin: {}", plugin.unwrap());
+    }
+
+    // Get plugin parameters for both agents
+    let (_mem_list1, _params) = agent1.get_plugin_params("UCX").unwrap();
+    let (_mem_list2, params) = agent2.get_plugin_params("UCX").unwrap();
+
+    // Create backends for both agents
+    let backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    // Create optional arguments and add backends
+    let mut opt_args = OptArgs::new().unwrap();
+    opt_args.add_backend(&backend1).unwrap();
+    opt_args.add_backend(&backend2).unwrap();
+
+    // Allocate and initialize memory regions
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
 
     // Initialize memory patterns
     storage1.memset(0xbb);
@@ -540,6 +1779,2019 @@ fn test_basic_agent_lifecycle() {
     assert!(storage2.as_slice().iter().all(|&x| x == 0xbb));
 }
 
+#[test]
+fn test_xfer_read() {
+    let agent1 = Agent::new("xfer_read_A1").unwrap();
+    let agent2 = Agent::new("xfer_read_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(1024).unwrap();
+    let mut storage2 = SystemStorage::new(1024).unwrap();
+    storage1.memset(0x00);
+    storage2.fill_with(|i| (i % 253) as u8);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let mut xfer_args = OptArgs::new().unwrap();
+    xfer_args.set_has_notification(true).unwrap();
+    xfer_args.set_notification_message(b"read done").unwrap();
+
+    // The local list is the destination of a read, the remote list its source
+    let req = agent1
+        .create_xfer_req(
+            XferOp::Read,
+            &local,
+            &remote,
+            &remote_name,
+            Some(&xfer_args),
+        )
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+    while agent1.get_xfer_status(&req).unwrap() {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    assert_eq!(storage1.as_slice(), storage2.as_slice());
+
+    // Reads notify the remote agent, just like writes
+    let mut notifs = NotificationMap::new().unwrap();
+    let start = std::time::Instant::now();
+    while !notifs.contains("xfer_read_A1").unwrap() {
+        assert!(
+            start.elapsed().as_secs() < 5,
+            "Timed out waiting for notification"
+        );
+        agent2.get_notifications(&mut notifs, None).unwrap();
+    }
+    let notify_map = notifs.take_notifs().unwrap();
+    assert_eq!(notify_map["xfer_read_A1"], vec!["read done".to_string()]);
+}
+
+#[test]
+fn test_create_backend_fallback() {
+    let agent = Agent::new("fallback_agent").unwrap();
+    let _backend = agent
+        .create_backend_fallback(&["NONEXISTENT", "UCX"])
+        .unwrap();
+    assert!(agent.get_backend("UCX").is_some());
+    assert!(agent.get_backend("NONEXISTENT").is_none());
+
+    assert!(matches!(
+        agent.create_backend_fallback(&["NONEXISTENT"]),
+        Err(NixlError::NoBackendAvailable(_))
+    ));
+}
+
+#[test]
+fn test_agent_ping() {
+    let agent1 = Agent::new("ping_A1").unwrap();
+    let agent2 = Agent::new("ping_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(64).unwrap();
+    let mut storage2 = SystemStorage::new(64).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let metadata = agent2.get_local_md().unwrap();
+    let remote_name = agent1.load_remote_md(&metadata).unwrap();
+    agent2
+        .load_remote_md(&agent1.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local_descs = XferDescList::new(MemType::Dram, false).unwrap();
+    local_descs.add_storage_desc(&storage1).unwrap();
+    let mut remote_descs = XferDescList::new(MemType::Dram, false).unwrap();
+    remote_descs.add_storage_desc(&storage2).unwrap();
+
+    // The remote agent echoes pings until both runs are done
+    let done = std::sync::atomic::AtomicBool::new(false);
+    let (stats, xfer_stats) = std::thread::scope(|scope| {
+        scope.spawn(|| {
+            while !done.load(std::sync::atomic::Ordering::Acquire) {
+                agent2.answer_pings().unwrap();
+                std::thread::yield_now();
+            }
+        });
+        let stats = agent1.ping(&remote_name, 10);
+        let xfer_stats = agent1.ping_xfer(&remote_name, &local_descs, &remote_descs, 10);
+        done.store(true, std::sync::atomic::Ordering::Release);
+        (stats.unwrap(), xfer_stats.unwrap())
+    });
+    for stats in [stats, xfer_stats] {
+        assert!(stats.median > std::time::Duration::ZERO);
+        assert!(stats.min <= stats.median);
+        assert!(stats.median <= stats.p99);
+        assert!(stats.p99 <= stats.max);
+    }
+
+    // Pings were consumed and nothing else is pending
+    let mut notifs = NotificationMap::new().unwrap();
+    agent1.get_notifications(&mut notifs, None).unwrap();
+    agent2.get_notifications(&mut notifs, None).unwrap();
+    assert!(notifs.is_empty().unwrap());
+
+    assert!(matches!(
+        agent1.ping(&remote_name, 0),
+        Err(NixlError::InvalidParam)
+    ));
+    assert!(matches!(
+        agent1.ping_xfer(&remote_name, &local_descs, &remote_descs, 0),
+        Err(NixlError::InvalidParam)
+    ));
+}
+
+#[test]
+fn test_xfer_selected_backend() {
+    let agent1 = Agent::new("select_A1").unwrap();
+    let agent2 = Agent::new("select_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _ucx1 = agent1.create_backend("UCX", &params).unwrap();
+    let _ucx2 = agent2.create_backend("UCX", &params).unwrap();
+    let posix = create_posix_backend(&agent1);
+
+    let mut storage1 = SystemStorage::new(64).unwrap();
+    let mut storage2 = SystemStorage::new(64).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let metadata = agent2.get_local_md().unwrap();
+    let remote_name = agent1.load_remote_md(&metadata).unwrap();
+
+    let mut local_descs = XferDescList::new(MemType::Dram, false).unwrap();
+    local_descs.add_storage_desc(&storage1).unwrap();
+    let mut remote_descs = XferDescList::new(MemType::Dram, false).unwrap();
+    remote_descs.add_storage_desc(&storage2).unwrap();
+
+    let req = agent1
+        .create_xfer_req(
+            XferOp::Write,
+            &local_descs,
+            &remote_descs,
+            &remote_name,
+            None,
+        )
+        .unwrap();
+
+    let selected = req.selected_backend().unwrap();
+    let mut candidates = vec!["UCX"];
+    if posix.is_some() {
+        candidates.push("POSIX");
+    }
+    assert!(candidates.contains(&selected.as_str()));
+}
+
+#[test]
+fn test_default_backend() {
+    let agent1 = Agent::new("default_backend_A1").unwrap();
+    let agent2 = Agent::new("default_backend_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let ucx1 = agent1.create_backend("UCX", &params).unwrap();
+    let _ucx2 = agent2.create_backend("UCX", &params).unwrap();
+    let _posix = create_posix_backend(&agent1);
+
+    assert!(agent1.default_backend().is_none());
+    agent1.set_default_backend(&ucx1);
+    assert!(agent1.default_backend().is_some());
+
+    let mut storage1 = SystemStorage::new(64).unwrap();
+    let mut storage2 = SystemStorage::new(64).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let metadata = agent2.get_local_md().unwrap();
+    let remote_name = agent1.load_remote_md(&metadata).unwrap();
+
+    let mut local_descs = XferDescList::new(MemType::Dram, false).unwrap();
+    local_descs.add_storage_desc(&storage1).unwrap();
+    let mut remote_descs = XferDescList::new(MemType::Dram, false).unwrap();
+    remote_descs.add_storage_desc(&storage2).unwrap();
+
+    let req = agent1
+        .create_xfer_req(
+            XferOp::Write,
+            &local_descs,
+            &remote_descs,
+            &remote_name,
+            None,
+        )
+        .unwrap();
+    assert_eq!(req.selected_backend().unwrap(), "UCX");
+}
+
+#[test]
+fn test_register_memory_as() {
+    let agent = Agent::new("register_as_agent").unwrap();
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+
+    let storage = SystemStorage::new(1024).unwrap();
+    assert!(matches!(
+        agent.register_memory_as(&storage, &[], None),
+        Err(NixlError::InvalidParam)
+    ));
+
+    let handles = agent
+        .register_memory_as(&storage, &[MemType::Dram], None)
+        .unwrap();
+    assert_eq!(handles.len(), 1);
+    assert_eq!(
+        handles[0].agent_name(),
+        Some("register_as_agent".to_string())
+    );
+}
+
+#[cfg(feature = "cuda")]
+extern "C" {
+    fn cudaMallocManaged(ptr: *mut *mut std::ffi::c_void, size: usize, flags: u32) -> i32;
+    fn cudaFree(ptr: *mut std::ffi::c_void) -> i32;
+}
+
+/// CUDA managed memory, addressable from both the host and the device
+#[cfg(feature = "cuda")]
+#[derive(Debug)]
+struct ManagedBuffer {
+    ptr: usize,
+    size: usize,
+}
+
+#[cfg(feature = "cuda")]
+impl Drop for ManagedBuffer {
+    fn drop(&mut self) {
+        unsafe { cudaFree(self.ptr as *mut std::ffi::c_void) };
+    }
+}
+
+#[cfg(feature = "cuda")]
+impl MemoryRegion for ManagedBuffer {
+    unsafe fn as_ptr(&self) -> *const u8 {
+        self.ptr as *const u8
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+}
+
+#[cfg(feature = "cuda")]
+impl NixlDescriptor for ManagedBuffer {
+    fn mem_type(&self) -> MemType {
+        MemType::Vram
+    }
+
+    fn device_id(&self) -> u64 {
+        0
+    }
+}
+
+#[cfg(feature = "cuda")]
+#[test]
+fn test_register_memory_as_transfers() {
+    const SIZE: usize = 256;
+
+    let mut ptr = std::ptr::null_mut();
+    // cudaMemAttachGlobal
+    if unsafe { cudaMallocManaged(&mut ptr, SIZE, 1) } != 0 {
+        println!("No usable CUDA device, skipping test");
+        return;
+    }
+    let buffer = ManagedBuffer {
+        ptr: ptr as usize,
+        size: SIZE,
+    };
+    unsafe { std::slice::from_raw_parts_mut(ptr.cast::<u8>(), SIZE) }.fill(0x6e);
+
+    let agent1 = Agent::new("register_as_xfer_A1").unwrap();
+    let agent2 = Agent::new("register_as_xfer_A2").unwrap();
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let ucx1 = agent1.create_backend("UCX", &params).unwrap();
+    let _ucx2 = agent2.create_backend("UCX", &params).unwrap();
+    let _posix = create_posix_backend(&agent1);
+    agent1.set_default_backend(&ucx1);
+
+    // Registered through the default backend only, once per memory type
+    let _handles = match agent1.register_memory_as(&buffer, &[MemType::Dram, MemType::Vram], None) {
+        Ok(handles) => handles,
+        Err(NixlError::BackendError) => {
+            println!("UCX cannot register CUDA memory, skipping test");
+            return;
+        }
+        Err(e) => panic!("Failed to register managed memory: {e}"),
+    };
+
+    let mut dest = SystemStorage::new(2 * SIZE).unwrap();
+    dest.register(&agent2, None).unwrap();
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    let dest_addr = unsafe { dest.as_ptr() } as usize;
+
+    for (index, mem_type) in [MemType::Dram, MemType::Vram].into_iter().enumerate() {
+        let mut local = XferDescList::new(mem_type, false).unwrap();
+        local.add_desc(buffer.ptr, SIZE, 0).unwrap();
+        let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+        remote.add_desc(dest_addr + index * SIZE, SIZE, 0).unwrap();
+
+        let req = agent1
+            .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+            .unwrap();
+        assert_eq!(req.selected_backend().unwrap(), "UCX");
+        agent1.post_xfer_req(&req, None).unwrap();
+        while agent1.get_xfer_status(&req).unwrap() {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+    }
+    assert!(dest.as_slice().iter().all(|&x| x == 0x6e));
+}
+
+#[test]
+fn test_self_notification() {
+    let agent1 = Agent::new("self_notif_A1").unwrap();
+    let agent2 = Agent::new("self_notif_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
+    storage1.memset(0xcc);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let metadata = agent2.get_local_md().unwrap();
+    let remote_name = agent1.load_remote_md(&metadata).unwrap();
+
+    let mut local_descs = XferDescList::new(MemType::Dram, false).unwrap();
+    local_descs.add_storage_desc(&storage1).unwrap();
+    let mut remote_descs = XferDescList::new(MemType::Dram, false).unwrap();
+    remote_descs.add_storage_desc(&storage2).unwrap();
+
+    let req = agent1
+        .create_xfer_req(
+            XferOp::Write,
+            &local_descs,
+            &remote_descs,
+            &remote_name,
+            None,
+        )
+        .unwrap();
+
+    let mut post_args = OptArgs::new().unwrap();
+    post_args.set_self_notification(b"write done");
+    assert_eq!(post_args.self_notification(), Some(&b"write done"[..]));
+    agent1.post_xfer_req(&req, Some(&post_args)).unwrap();
+
+    let mut notifs = NotificationMap::new().unwrap();
+    while !notifs.contains("self_notif_A1").unwrap() {
+        agent1.get_notifications(&mut notifs, None).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    let notify_map = notifs.take_notifs().unwrap();
+    assert_eq!(notify_map["self_notif_A1"], vec!["write done".to_string()]);
+    assert!(storage2.as_slice().iter().all(|&x| x == 0xcc));
+
+    // A request dropped after completing still delivers its self notification
+    let req = agent1
+        .create_xfer_req(
+            XferOp::Write,
+            &local_descs,
+            &remote_descs,
+            &remote_name,
+            None,
+        )
+        .unwrap();
+    post_args.set_self_notification(b"dropped");
+    agent1.post_xfer_req(&req, Some(&post_args)).unwrap();
+    while agent1.get_xfer_status(&req).unwrap() {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    drop(req);
+
+    let mut notifs = NotificationMap::new().unwrap();
+    agent1.get_notifications(&mut notifs, None).unwrap();
+    let notify_map = notifs.take_notifs().unwrap();
+    assert_eq!(notify_map["self_notif_A1"], vec!["dropped".to_string()]);
+}
+
+#[test]
+fn test_append_crc() {
+    const PAYLOAD: usize = 1024;
+    const SIZE: usize = PAYLOAD + CRC_LEN;
+
+    let agent1 = Agent::new("append_crc_A1").unwrap();
+    let agent2 = Agent::new("append_crc_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(SIZE).unwrap();
+    let mut storage2 = SystemStorage::new(SIZE).unwrap();
+    let mut storage3 = SystemStorage::new(SIZE).unwrap();
+    storage1.fill_with(|i| i as u8);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+    storage3.register(&agent1, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut crc_args = OptArgs::new().unwrap();
+    crc_args.set_append_crc(true);
+    assert!(crc_args.append_crc());
+
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let write = agent1
+        .create_xfer_req(
+            XferOp::Write,
+            &local,
+            &remote,
+            &remote_name,
+            Some(&crc_args),
+        )
+        .unwrap();
+    agent1.post_xfer_req(&write, None).unwrap();
+    while agent1.get_xfer_status(&write).unwrap() {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    assert_eq!(storage2.as_slice(), storage1.as_slice());
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage3).unwrap();
+    let read = agent1
+        .create_xfer_req(XferOp::Read, &local, &remote, &remote_name, Some(&crc_args))
+        .unwrap();
+    agent1.post_xfer_req(&read, None).unwrap();
+    while agent1.get_xfer_status(&read).unwrap() {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    assert_eq!(
+        &storage3.as_slice()[..PAYLOAD],
+        &storage1.as_slice()[..PAYLOAD]
+    );
+
+    // Corrupt the payload but keep the stored CRC
+    drop(remote);
+    let crc = storage2.as_slice()[PAYLOAD..].to_vec();
+    storage2.fill_with(|i| if i < PAYLOAD { 0xff } else { crc[i - PAYLOAD] });
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let corrupt = agent1
+        .create_xfer_req(XferOp::Read, &local, &remote, &remote_name, Some(&crc_args))
+        .unwrap();
+    agent1.post_xfer_req(&corrupt, None).unwrap();
+    let result = loop {
+        match agent1.get_xfer_status(&corrupt) {
+            Ok(true) => std::thread::sleep(std::time::Duration::from_millis(1)),
+            other => break other,
+        }
+    };
+    assert!(matches!(result, Err(NixlError::CrcMismatch { index: 0 })));
+}
+
+struct IdentityTransform;
+
+impl DataTransform for IdentityTransform {
+    fn encode(&self, src: &[u8]) -> Vec<u8> {
+        src.to_vec()
+    }
+
+    fn decode(&self, src: &[u8]) -> Vec<u8> {
+        src.to_vec()
+    }
+}
+
+struct XorTransform(u8);
+
+impl DataTransform for XorTransform {
+    fn encode(&self, src: &[u8]) -> Vec<u8> {
+        src.iter().map(|b| b ^ self.0).collect()
+    }
+
+    fn decode(&self, src: &[u8]) -> Vec<u8> {
+        self.encode(src)
+    }
+}
+
+#[test]
+fn test_transform() {
+    const SIZE: usize = 1024;
+
+    let agent1 = Agent::new("transform_A1").unwrap();
+    let agent2 = Agent::new("transform_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(SIZE).unwrap();
+    let mut storage2 = SystemStorage::new(SIZE).unwrap();
+    let mut storage3 = SystemStorage::new(SIZE).unwrap();
+    storage1.fill_with(|i| (i % 251) as u8);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+    storage3.register(&agent1, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let transforms: [(Arc<dyn DataTransform>, u8); 2] = [
+        (Arc::new(IdentityTransform), 0),
+        (Arc::new(XorTransform(0x5a)), 0x5a),
+    ];
+    for (transform, key) in transforms {
+        let mut args = OptArgs::new().unwrap();
+        args.set_transform(transform);
+        assert!(args.transform().is_some());
+
+        let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+        remote.add_storage_desc(&storage2).unwrap();
+
+        let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+        local.add_storage_desc(&storage1).unwrap();
+        let write = agent1
+            .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, Some(&args))
+            .unwrap();
+        agent1.post_xfer_req(&write, None).unwrap();
+        while agent1.get_xfer_status(&write).unwrap() {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        // The remote holds the encoded data, and the source is left untouched
+        assert!(storage2
+            .as_slice()
+            .iter()
+            .zip(storage1.as_slice())
+            .all(|(&remote, &local)| remote == local ^ key));
+        assert_eq!(storage1.as_slice()[1], 1);
+
+        let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+        local.add_storage_desc(&storage3).unwrap();
+        let read = agent1
+            .create_xfer_req(XferOp::Read, &local, &remote, &remote_name, Some(&args))
+            .unwrap();
+        agent1.post_xfer_req(&read, None).unwrap();
+        while agent1.get_xfer_status(&read).unwrap() {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        assert_eq!(storage3.as_slice(), storage1.as_slice());
+
+        // Polling again does not decode over data written since completion
+        drop(local);
+        storage3.memset(0);
+        assert!(!agent1.get_xfer_status(&read).unwrap());
+        assert!(storage3.as_slice().iter().all(|&b| b == 0));
+    }
+}
+
+#[test]
+fn test_transform_dropped_in_progress() {
+    const SIZE: usize = 64 * 1024 * 1024;
+
+    let agent1 = Agent::new("transform_drop_A1").unwrap();
+    let agent2 = Agent::new("transform_drop_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(SIZE).unwrap();
+    let mut storage2 = SystemStorage::new(SIZE).unwrap();
+    storage1.fill_with(|i| (i % 251) as u8);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut args = OptArgs::new().unwrap();
+    args.set_transform(Arc::new(XorTransform(0x5a)));
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, Some(&args))
+        .unwrap();
+    // The scratch buffer is registered alongside storage1
+    assert_eq!(agent1.registration_overhead().unwrap().count(), 2);
+
+    agent1.post_xfer_req(&req, None).unwrap();
+    drop(req);
+
+    // Shutting down waits for the dropped request, whose scratch buffer must
+    // stay registered and alive until the write has finished
+    agent1.clone().shutdown().unwrap();
+    assert_eq!(agent1.registration_overhead().unwrap().count(), 1);
+    assert!(storage2
+        .as_slice()
+        .iter()
+        .zip(storage1.as_slice())
+        .all(|(&remote, &local)| remote == local ^ 0x5a));
+}
+
+#[test]
+fn test_allow_overlaps() {
+    let agent1 = Agent::new("allow_overlaps_A1").unwrap();
+    let agent2 = Agent::new("allow_overlaps_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
+    storage1.memset(0x42);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let local_addr = storage1.as_slice().as_ptr() as usize;
+    let remote_addr = storage2.as_slice().as_ptr() as usize;
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_desc(local_addr, 128, 0).unwrap();
+    local.add_desc(local_addr + 128, 128, 0).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_desc(remote_addr + 64, 128, 0).unwrap();
+    remote.add_desc(remote_addr, 128, 0).unwrap();
+    assert!(remote.has_overlaps().unwrap());
+
+    let result = agent1.create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None);
+    assert!(matches!(result, Err(NixlError::OverlappingDescriptors)));
+
+    // A destination that wraps the address space is reported, not summed
+    let mut wrapping = XferDescList::new(MemType::Dram, false).unwrap();
+    wrapping.add_desc(remote_addr, 128, 0).unwrap();
+    wrapping.add_desc(usize::MAX - 64, 128, 0).unwrap();
+    let result = agent1.create_xfer_req(XferOp::Write, &local, &wrapping, &remote_name, None);
+    assert!(matches!(
+        result,
+        Err(NixlError::DescriptorOverflow { index: 1 })
+    ));
+
+    let mut overlap_args = OptArgs::new().unwrap();
+    overlap_args.set_allow_overlaps(true);
+    assert!(overlap_args.allow_overlaps());
+    let req = agent1
+        .create_xfer_req(
+            XferOp::Write,
+            &local,
+            &remote,
+            &remote_name,
+            Some(&overlap_args),
+        )
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+    while agent1.get_xfer_status(&req).unwrap() {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    assert!(storage2.as_slice()[..192].iter().all(|&x| x == 0x42));
+}
+
+#[test]
+fn test_backend_supported_ops() {
+    let agent = Agent::new("supported_ops_agent").unwrap();
+    let (backend, _opt_args) = match create_posix_backend(&agent) {
+        Some(result) => result,
+        None => return,
+    };
+
+    let ops = backend.supported_ops(MemType::File).unwrap();
+    assert!(ops.contains(&XferOp::Read));
+    assert!(ops.contains(&XferOp::Write));
+
+    assert!(backend.supported_ops(MemType::Object).unwrap().is_empty());
+}
+
+#[test]
+fn test_create_xfer_req_unsupported_mem_types() {
+    let agent = Agent::new("unsupported_mem_agent").unwrap();
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let ucx = agent.create_backend("UCX", &params).unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_desc(0x1000, 0x100, 0).unwrap();
+    let mut remote = XferDescList::new(MemType::File, false).unwrap();
+    remote.add_desc(0, 0x100, 0).unwrap();
+
+    // UCX registers DRAM but not files, so the remote side cannot be served
+    let result = agent.create_xfer_req(
+        XferOp::Write,
+        &local,
+        &remote,
+        "unsupported_mem_agent",
+        None,
+    );
+    assert!(matches!(result, Err(NixlError::OpUnsupported)));
+
+    // Selecting UCX explicitly is rejected even when another backend could serve it
+    if create_posix_backend(&agent).is_some() {
+        let mut opt_args = OptArgs::new().unwrap();
+        opt_args.add_backend(&ucx).unwrap();
+        let result = agent.create_xfer_req(
+            XferOp::Write,
+            &local,
+            &remote,
+            "unsupported_mem_agent",
+            Some(&opt_args),
+        );
+        assert!(matches!(result, Err(NixlError::OpUnsupported)));
+    }
+}
+
+#[test]
+fn test_backend_supported_mem_types() {
+    let agent = Agent::new("mem_types_agent").unwrap();
+    let (mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let backend = agent.create_backend("UCX", &params).unwrap();
+
+    let mem_types = backend.supported_mem_types().unwrap();
+    assert!(mem_types.contains(&MemType::Dram));
+    assert!(!mem_types.contains(&MemType::File));
+    assert_eq!(mem_types.len(), mems.len().unwrap());
+}
+
+#[test]
+fn test_probe_remote() {
+    let agent1 = Agent::new("probe_A1").unwrap();
+    let agent2 = Agent::new("probe_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage = SystemStorage::new(1024).unwrap();
+    storage.register(&agent2, None).unwrap();
+
+    let metadata = agent2.get_local_md().unwrap();
+    let remote_name = agent1.load_remote_md(&metadata).unwrap();
+
+    let addr = unsafe { storage.as_ptr() } as usize;
+    let mut remote_descs = XferDescList::new(MemType::Dram, false).unwrap();
+    remote_descs.add_desc(addr, 512, 0).unwrap();
+    assert!(agent1.probe_remote(&remote_descs, &remote_name).is_ok());
+
+    // Runs past the end of the registered region
+    remote_descs.add_desc(addr + 512, 1024, 0).unwrap();
+    assert!(matches!(
+        agent1.probe_remote(&remote_descs, &remote_name),
+        Err(NixlError::RemoteRegionNotFound { index: 1 })
+    ));
+}
+
+#[test]
+fn test_dry_run_xfer() {
+    let agent1 = Agent::new("dry_run_A1").unwrap();
+    let agent2 = Agent::new("dry_run_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
+    storage1.memset(0x11);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+    let remote_addr = unsafe { storage2.as_ptr() } as usize;
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let report = agent1
+        .dry_run_xfer(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+    assert!(report.is_valid());
+    assert_eq!(report.descriptor_count, 1);
+    assert_eq!(report.total_bytes, 256);
+    assert_eq!(report.backend.as_deref(), Some("UCX"));
+
+    // Nothing was transferred
+    assert!(storage2.as_slice().iter().all(|&x| x == 0));
+
+    // A transform is checked without staging the data
+    let mut args = OptArgs::new().unwrap();
+    args.set_transform(Arc::new(XorTransform(0x5a)));
+    args.set_notification_message(b"dry").unwrap();
+    args.set_notify_with_sequence(true);
+    let report = agent1
+        .dry_run_xfer(XferOp::Write, &local, &remote, &remote_name, Some(&args))
+        .unwrap();
+    assert!(report.is_valid());
+    assert_eq!(report.backend.as_deref(), Some("UCX"));
+    assert!(storage2.as_slice().iter().all(|&x| x == 0));
+
+    // The remote range runs past the registered region
+    let mut bad_remote = XferDescList::new(MemType::Dram, false).unwrap();
+    bad_remote.add_desc(remote_addr + 128, 256, 0).unwrap();
+    let report = agent1
+        .dry_run_xfer(XferOp::Write, &local, &bad_remote, &remote_name, None)
+        .unwrap();
+    assert!(!report.is_valid());
+    assert_eq!(report.backend, None);
+    assert!(report
+        .problems
+        .iter()
+        .any(|e| matches!(e, NixlError::RemoteRegionNotFound { index: 0 })));
+}
+
+#[test]
+fn test_double_buffer_streaming() {
+    const CHUNK: usize = 64;
+    const CHUNKS: usize = 10;
+
+    let agent1 = Agent::new("double_buffer_A1").unwrap();
+    let agent2 = Agent::new("double_buffer_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut dest = SystemStorage::new(CHUNK * CHUNKS).unwrap();
+    dest.register(&agent2, None).unwrap();
+    let dest_addr = unsafe { dest.as_ptr() } as usize;
+
+    let metadata = agent2.get_local_md().unwrap();
+    let remote_name = agent1.load_remote_md(&metadata).unwrap();
+
+    let mut buffers = DoubleBuffer::new(&agent1, CHUNK, None).unwrap();
+    for chunk in 0..CHUNKS {
+        let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+        remote
+            .add_desc(dest_addr + chunk * CHUNK, CHUNK, 0)
+            .unwrap();
+
+        buffers.current().memset(chunk as u8);
+        buffers
+            .fill_and_swap(XferOp::Write, &remote, &remote_name)
+            .unwrap();
+    }
+    buffers.finish().unwrap();
+
+    for (chunk, data) in dest.as_slice().chunks(CHUNK).enumerate() {
+        assert!(data.iter().all(|&x| x == chunk as u8));
+    }
+}
+
+#[test]
+fn test_xfer_group_poll_completed() {
+    const CHUNK: usize = 1024;
+
+    let agent1 = Agent::new("xfer_group_A1").unwrap();
+    let agent2 = Agent::new("xfer_group_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut source = SystemStorage::new(CHUNK * 3).unwrap();
+    let mut dest = SystemStorage::new(CHUNK * 3).unwrap();
+    source.fill_with(|i| (i / CHUNK) as u8 + 1);
+    source.register(&agent1, None).unwrap();
+    dest.register(&agent2, None).unwrap();
+    let source_addr = unsafe { source.as_ptr() } as usize;
+    let dest_addr = unsafe { dest.as_ptr() } as usize;
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut group = XferGroup::new(&agent1);
+    for chunk in 0..3 {
+        let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+        local
+            .add_desc(source_addr + chunk * CHUNK, CHUNK, 0)
+            .unwrap();
+        let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+        remote
+            .add_desc(dest_addr + chunk * CHUNK, CHUNK, 0)
+            .unwrap();
+
+        let req = agent1
+            .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+            .unwrap();
+        agent1.post_xfer_req(&req, None).unwrap();
+        assert_eq!(group.add(req), chunk);
+    }
+
+    let mut completed = Vec::new();
+    let start = std::time::Instant::now();
+    while !group.is_done() {
+        assert!(
+            start.elapsed().as_secs() < 5,
+            "Timed out waiting for transfers"
+        );
+        completed.extend(group.poll_completed().unwrap());
+    }
+    assert!(group.poll_completed().unwrap().is_empty());
+
+    completed.sort_unstable();
+    assert_eq!(completed, vec![0, 1, 2]);
+    assert_eq!(dest.as_slice(), source.as_slice());
+}
+
+#[test]
+fn test_write_if_equal() {
+    let agent1 = Agent::new("write_if_equal_A1").unwrap();
+    let agent2 = Agent::new("write_if_equal_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let guard_value = 7u64.to_ne_bytes();
+    let mut guard = SystemStorage::new(8).unwrap();
+    guard.fill_with(|i| guard_value[i]);
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
+    storage1.memset(0x11);
+    storage2.memset(0x00);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+    guard.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut remote_guard = XferDescList::new(MemType::Dram, false).unwrap();
+    remote_guard.add_storage_desc(&guard).unwrap();
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let proceeded = agent1
+        .write_if_equal(&remote_guard, 8, &local, &remote, &remote_name, None)
+        .unwrap();
+    assert!(!proceeded);
+    assert!(storage2.as_slice().iter().all(|&x| x == 0x00));
+
+    let proceeded = agent1
+        .write_if_equal(&remote_guard, 7, &local, &remote, &remote_name, None)
+        .unwrap();
+    assert!(proceeded);
+    assert!(storage2.as_slice().iter().all(|&x| x == 0x11));
+
+    // The guard must be a single 8-byte descriptor
+    assert!(agent1
+        .write_if_equal(&remote, 7, &local, &remote, &remote_name, None)
+        .is_err());
+}
+
+#[test]
+fn test_striped_write() {
+    const SIZE: usize = 4096;
+
+    let agent1 = Agent::new("striped_A1").unwrap();
+    let agent2 = Agent::new("striped_A2").unwrap();
+
+    let plugins = agent1.get_available_plugins().unwrap();
+    if !plugins
+        .iter()
+        .any(|p| p.map(|s| s == "UCX_MO").unwrap_or(false))
+    {
+        println!("UCX_MO plugin not available, skipping test");
+        return;
+    }
+
+    let mut backends = Vec::new();
+    for plugin in ["UCX", "UCX_MO"] {
+        let (_mems, params) = agent1.get_plugin_params(plugin).unwrap();
+        backends.push(agent1.create_backend(plugin, &params).unwrap());
+        agent2.create_backend(plugin, &params).unwrap();
+    }
+
+    let mut source = SystemStorage::new(SIZE).unwrap();
+    let mut dest = SystemStorage::new(SIZE).unwrap();
+    source.fill_with(|i| (i % 251) as u8);
+    source.register(&agent1, None).unwrap();
+    dest.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&source).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&dest).unwrap();
+
+    let backend_refs: Vec<&Backend> = backends.iter().collect();
+    let mut group = agent1
+        .striped_write(&local, &remote, &remote_name, &backend_refs, None)
+        .unwrap();
+    assert_eq!(group.len(), 2);
+
+    let start = std::time::Instant::now();
+    while !group.is_done() {
+        assert!(
+            start.elapsed().as_secs() < 5,
+            "Timed out waiting for stripes"
+        );
+        group.poll_completed().unwrap();
+    }
+
+    assert_eq!(dest.as_slice(), source.as_slice());
+
+    // Only the last stripe notifies, with the length of the whole write
+    let mut args = OptArgs::new().unwrap();
+    args.set_notification_message(b"striped").unwrap();
+    args.set_notify_with_length(true);
+    let mut group = agent1
+        .striped_write(&local, &remote, &remote_name, &backend_refs, Some(&args))
+        .unwrap();
+    let mut notifs = NotificationMap::new().unwrap();
+    let start = std::time::Instant::now();
+    while !group.is_done() || !notifs.contains("striped_A1").unwrap() {
+        assert!(
+            start.elapsed().as_secs() < 5,
+            "Timed out waiting for stripes"
+        );
+        group.poll_completed().unwrap();
+        agent2.get_notifications(&mut notifs, None).unwrap();
+    }
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    agent2.get_notifications(&mut notifs, None).unwrap();
+
+    let mut expected = (SIZE as u64).to_le_bytes().to_vec();
+    expected.extend_from_slice(b"striped");
+    assert_eq!(
+        notifs.drain_sender("striped_A1").unwrap(),
+        Some(vec![expected])
+    );
+
+    args.set_append_crc(true);
+    assert!(matches!(
+        agent1.striped_write(&local, &remote, &remote_name, &backend_refs, Some(&args)),
+        Err(NixlError::InvalidParam)
+    ));
+}
+
+#[test]
+fn test_ucx_params() {
+    let agent = Agent::new("ucx_params_agent").unwrap();
+    let (_mems, defaults) = agent.get_plugin_params("UCX").unwrap();
+    let ucx = UcxParams::from_params(&defaults).unwrap();
+    assert_eq!(ucx.num_workers, Some(1));
+    assert_eq!(ucx.error_handling_mode.as_deref(), Some("peer"));
+
+    let params = UcxParams {
+        num_workers: Some(2),
+        split_batch_size: Some(64),
+        ..ucx
+    }
+    .into_params()
+    .unwrap();
+    assert_eq!(params.get("num_workers").unwrap().as_deref(), Some("2"));
+    assert_eq!(params.get("num_threads").unwrap(), None);
+
+    // The backend keeps the parameters it was created with
+    let backend = agent.create_backend("UCX", &params).unwrap();
+    let (_mems, created) = agent.get_backend_params(&backend).unwrap();
+    let ucx = UcxParams::from_params(&created).unwrap();
+    assert_eq!(ucx.num_workers, Some(2));
+    assert_eq!(ucx.split_batch_size, Some(64));
+}
+
+#[test]
+fn test_backend_max_transfer_size() {
+    let agent = Agent::new("max_size_agent").unwrap();
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let backend = agent.create_backend("UCX", &params).unwrap();
+
+    // No limit was declared when the backend was created
+    assert_eq!(backend.max_transfer_size(MemType::Dram).unwrap(), None);
+    assert!(matches!(
+        backend.max_transfer_size(MemType::File),
+        Err(NixlError::InvalidParam)
+    ));
+}
+
+#[test]
+fn test_write_large() {
+    const SIZE: usize = 10 * 1024;
+    const LIMIT: usize = 4096;
+
+    let agent1 = Agent::new("write_large_A1").unwrap();
+    let agent2 = Agent::new("write_large_A2").unwrap();
+
+    // Declare a small limit for the backend
+    let (_mems, mut params) = agent1.get_plugin_params("UCX").unwrap();
+    params
+        .set(MAX_TRANSFER_SIZE_PARAM, &LIMIT.to_string())
+        .unwrap();
+    let backend = agent1.create_backend("UCX", &params).unwrap();
+    agent2.create_backend("UCX", &params).unwrap();
+    assert_eq!(
+        backend.max_transfer_size(MemType::Dram).unwrap(),
+        Some(LIMIT)
+    );
+    // The limit is read by the bindings and is not passed to the plugin
+    let (_mems, plugin_params) = agent1.get_backend_params(&backend).unwrap();
+    assert_eq!(plugin_params.get(MAX_TRANSFER_SIZE_PARAM).unwrap(), None);
+    assert!(params.get(MAX_TRANSFER_SIZE_PARAM).unwrap().is_some());
+
+    let mut source = SystemStorage::new(SIZE).unwrap();
+    let mut dest = SystemStorage::new(SIZE).unwrap();
+    source.fill_with(|i| (i % 251) as u8);
+    source.register(&agent1, None).unwrap();
+    dest.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&source).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&dest).unwrap();
+
+    // Posting the whole buffer at once exceeds the limit
+    assert!(matches!(
+        agent1.create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None),
+        Err(NixlError::TransferTooLarge { limit: LIMIT })
+    ));
+
+    {
+        let mut group = agent1
+            .write_large(&local, &remote, &remote_name, None)
+            .unwrap();
+        assert_eq!(group.len(), SIZE.div_ceil(LIMIT));
+
+        let start = std::time::Instant::now();
+        while !group.is_done() {
+            assert!(
+                start.elapsed().as_secs() < 5,
+                "Timed out waiting for chunks"
+            );
+            group.poll_completed().unwrap();
+        }
+    }
+
+    assert_eq!(dest.as_slice(), source.as_slice());
+}
+
+#[test]
+fn test_create_mixed_xfer_req() {
+    let agent1 = Agent::new("mixed_A1").unwrap();
+    let agent2 = Agent::new("mixed_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
+    storage1.memset(0xAA);
+    storage2.memset(0xBB);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    let local_addr = storage1.as_slice().as_ptr() as usize;
+    let remote_addr = storage2.as_slice().as_ptr() as usize;
+
+    let mut write_local = XferDescList::new(MemType::Dram, false).unwrap();
+    write_local.add_desc(local_addr, 128, 0).unwrap();
+    let mut write_remote = XferDescList::new(MemType::Dram, false).unwrap();
+    write_remote.add_desc(remote_addr, 128, 0).unwrap();
+    let mut read_local = XferDescList::new(MemType::Dram, false).unwrap();
+    read_local.add_desc(local_addr + 128, 128, 0).unwrap();
+    let mut read_remote = XferDescList::new(MemType::Dram, false).unwrap();
+    read_remote.add_desc(remote_addr + 128, 128, 0).unwrap();
+
+    assert!(matches!(
+        agent1.create_mixed_xfer_req(&[], &remote_name, None),
+        Err(NixlError::InvalidParam)
+    ));
+
+    {
+        let parts = [
+            (XferOp::Write, &write_local, &write_remote),
+            (XferOp::Read, &read_local, &read_remote),
+        ];
+        let mut group = agent1
+            .create_mixed_xfer_req(&parts, &remote_name, None)
+            .unwrap();
+        assert_eq!(group.len(), 2);
+
+        let start = std::time::Instant::now();
+        while !group.is_done() {
+            assert!(
+                start.elapsed().as_secs() < 5,
+                "Timed out waiting for transfers"
+            );
+            group.poll_completed().unwrap();
+        }
+    }
+
+    assert!(storage2.as_slice()[..128].iter().all(|&x| x == 0xAA));
+    assert!(storage1.as_slice()[128..].iter().all(|&x| x == 0xBB));
+}
+
+#[test]
+fn test_xfer_cost() {
+    let agent1 = Agent::new("xfer_cost_A1").unwrap();
+    let agent2 = Agent::new("xfer_cost_A2").unwrap();
+
+    let (_mems, mut params) = agent1.get_plugin_params("UCX").unwrap();
+    let backend = agent1.create_backend("UCX", &params).unwrap();
+    assert_eq!(backend.max_descriptors().unwrap(), None);
+    params.set(MAX_DESCRIPTORS_PARAM, "2").unwrap();
+    let backend2 = agent2.create_backend("UCX", &params).unwrap();
+    assert_eq!(backend2.max_descriptors().unwrap(), Some(2));
+    let (_mems, plugin_params) = agent2.get_backend_params(&backend2).unwrap();
+    assert_eq!(plugin_params.get(MAX_DESCRIPTORS_PARAM).unwrap(), None);
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    let local_addr = storage1.as_slice().as_ptr() as usize;
+    let remote_addr = storage2.as_slice().as_ptr() as usize;
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_desc(local_addr, 100, 0).unwrap();
+    local.add_desc(local_addr + 128, 50, 0).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_desc(remote_addr, 100, 0).unwrap();
+    remote.add_desc(remote_addr + 128, 50, 0).unwrap();
+
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+    let cost = agent1.xfer_cost(&req, None).unwrap();
+    assert_eq!(cost.total_bytes, 150);
+    assert_eq!(cost.descriptor_count, 2);
+}
+
+#[test]
+fn test_backend_outstanding_ops() {
+    const SIZE: usize = 64 * 1024 * 1024;
+    const REQUESTS: usize = 4;
+
+    let agent1 = Agent::new("outstanding_A1").unwrap();
+    let agent2 = Agent::new("outstanding_A2").unwrap();
+
+    let (_mems, mut params) = agent1.get_plugin_params("UCX").unwrap();
+    let backend2 = agent2.create_backend("UCX", &params).unwrap();
+    assert_eq!(backend2.queue_capacity().unwrap(), None);
+    params
+        .set(QUEUE_CAPACITY_PARAM, &REQUESTS.to_string())
+        .unwrap();
+    let backend = agent1.create_backend("UCX", &params).unwrap();
+    assert_eq!(backend.queue_capacity().unwrap(), Some(REQUESTS));
+    let (_mems, plugin_params) = agent1.get_backend_params(&backend).unwrap();
+    assert_eq!(plugin_params.get(QUEUE_CAPACITY_PARAM).unwrap(), None);
+
+    let mut storage1 = SystemStorage::new(SIZE).unwrap();
+    let mut storage2 = SystemStorage::new(SIZE).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let reqs: Vec<_> = (0..REQUESTS)
+        .map(|_| {
+            agent1
+                .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(backend.outstanding_ops().unwrap(), 0);
+
+    for req in &reqs {
+        agent1.post_xfer_req(req, None).unwrap();
+    }
+
+    // Completed requests never return to in progress, so the count taken
+    // first bounds the requests still running afterwards
+    let outstanding = backend.outstanding_ops().unwrap();
+    let in_progress = reqs
+        .iter()
+        .filter(|req| agent1.get_xfer_status(req).unwrap())
+        .count();
+    assert!(outstanding >= in_progress);
+    assert!(outstanding <= REQUESTS);
+
+    for req in &reqs {
+        while agent1.get_xfer_status(req).unwrap() {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+    }
+    assert_eq!(backend.outstanding_ops().unwrap(), 0);
+}
+
+#[test]
+fn test_agent_error_log() {
+    let agent = AgentBuilder::new("error_log_agent")
+        .track_errors(true)
+        .build()
+        .unwrap();
+    assert!(agent.recent_errors().is_empty());
+
+    // No metadata was loaded for this agent
+    assert!(agent.make_connection("unknown_remote").is_err());
+
+    let errors = agent.recent_errors();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0].1, NixlError::BackendError));
+
+    agent.clear_error_log();
+    assert!(agent.recent_errors().is_empty());
+
+    // Transfers rejected before reaching NIXL are logged as well
+    let (_mems, params) = agent.get_plugin_params("UCX").unwrap();
+    let _backend = agent.create_backend("UCX", &params).unwrap();
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_desc(0x1000, 0x100, 0).unwrap();
+    let mut remote = XferDescList::new(MemType::File, false).unwrap();
+    remote.add_desc(0, 0x100, 0).unwrap();
+    let result = agent.create_xfer_req(XferOp::Write, &local, &remote, "error_log_agent", None);
+    assert!(matches!(result, Err(NixlError::OpUnsupported)));
+    let errors = agent.recent_errors();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0].1, NixlError::OpUnsupported));
+
+    // Untracked agents never collect errors
+    let untracked = Agent::new("untracked_agent").unwrap();
+    assert!(untracked.make_connection("unknown_remote").is_err());
+    assert!(untracked.recent_errors().is_empty());
+}
+
+#[test]
+fn test_agent_builder_backends() {
+    let agent = AgentBuilder::new("builder_backend_agent")
+        .with_backend("UCX")
+        .with_backend_param("UCX", "num_workers", "2")
+        .build()
+        .unwrap();
+    let backend = agent.get_backend("UCX").unwrap();
+    let (_mems, params) = agent.get_backend_params(&backend).unwrap();
+    assert_eq!(params.get("num_workers").unwrap().as_deref(), Some("2"));
+
+    let opt_args = agent.default_opt_args().unwrap();
+    let storage = SystemStorage::new(1024).unwrap();
+    let _handle = agent.register_memory(&storage, Some(&opt_args)).unwrap();
+
+    let result = AgentBuilder::new("builder_missing_agent")
+        .with_backend("UCX")
+        .with_backend("NO_SUCH_PLUGIN")
+        .build();
+    assert!(matches!(result, Err(NixlError::PluginNotFound(name)) if name == "NO_SUCH_PLUGIN"));
+}
+
+#[test]
+fn test_agent_listener_addr() {
+    let agent = Agent::new("no_listener_agent").unwrap();
+    assert!(agent.listener_addr().is_none());
+
+    let agent = AgentBuilder::new("listener_agent")
+        .listen_on("127.0.0.1:0".parse().unwrap())
+        .build()
+        .unwrap();
+    let addr = agent.listener_addr().unwrap();
+    assert_eq!(addr.ip(), std::net::Ipv4Addr::LOCALHOST);
+    assert_ne!(addr.port(), 0);
+}
+
+#[test]
+fn test_remote_ring_buffer() {
+    const SLOTS: usize = 4;
+
+    let agent1 = Agent::new("ring_producer").unwrap();
+    let agent2 = Agent::new("ring_consumer").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut producer = RemoteRingBuffer::producer(&agent1, "ring_consumer", 32, SLOTS).unwrap();
+    let mut consumer = RemoteRingBuffer::consumer(&agent2, "ring_producer", 32).unwrap();
+
+    agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    agent2
+        .load_remote_md(&agent1.get_local_md().unwrap())
+        .unwrap();
+
+    let messages: Vec<Vec<u8>> = (0..10u8).map(|i| vec![i; i as usize + 1]).collect();
+    // Notifications that are not part of the ring are left to the application
+    agent1
+        .send_notification("ring_consumer", b"not_ring", None)
+        .unwrap();
+
+    // Nothing has been consumed yet, so the ring fills up
+    for message in &messages[..SLOTS] {
+        producer.push(message).unwrap();
+    }
+    assert!(matches!(
+        producer.push(&messages[SLOTS]),
+        Err(NixlError::RingBufferFull)
+    ));
+    assert!(matches!(
+        producer.push(&[0; 33]),
+        Err(NixlError::InvalidParam)
+    ));
+
+    let mut pushed = SLOTS;
+    let mut received = Vec::new();
+    let start = std::time::Instant::now();
+    while received.len() < messages.len() {
+        assert!(
+            start.elapsed().as_secs() < 5,
+            "Timed out waiting for ring messages"
+        );
+        if pushed < messages.len() {
+            match producer.push(&messages[pushed]) {
+                Ok(()) => pushed += 1,
+                Err(NixlError::RingBufferFull) => {}
+                Err(e) => panic!("Failed to push: {e:?}"),
+            }
+        }
+        if let Some(message) = consumer.pop().unwrap() {
+            received.push(message);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+
+    assert_eq!(received, messages);
+
+    let mut notifs = NotificationMap::new().unwrap();
+    agent2.get_notifications(&mut notifs, None).unwrap();
+    let sent = notifs.drain_sender("ring_producer").unwrap().unwrap();
+    assert_eq!(sent, vec![b"not_ring".to_vec()]);
+}
+
+/// Counts `info` events emitted while it is the default subscriber
+struct InfoEventCounter(std::sync::Arc<std::sync::atomic::AtomicUsize>);
+
+impl tracing::Subscriber for InfoEventCounter {
+    fn enabled(&self, metadata: &tracing::Metadata<'_>) -> bool {
+        *metadata.level() == tracing::Level::INFO
+    }
+    fn new_span(&self, _span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+        tracing::span::Id::from_u64(1)
+    }
+    fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+    fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+    fn event(&self, _event: &tracing::Event<'_>) {
+        self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+    fn enter(&self, _span: &tracing::span::Id) {}
+    fn exit(&self, _span: &tracing::span::Id) {}
+}
+
+#[test]
+fn test_wait_with_logging() {
+    const SIZE: usize = 64 * 1024 * 1024;
+
+    let agent1 = Agent::new("wait_logging_A1").unwrap();
+    let agent2 = Agent::new("wait_logging_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(SIZE).unwrap();
+    let mut storage2 = SystemStorage::new(SIZE).unwrap();
+    storage1.memset(0x5a);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+
+    let interval = std::time::Duration::from_millis(5);
+    let events = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let subscriber = InfoEventCounter(events.clone());
+    let waited = tracing::subscriber::with_default(subscriber, || {
+        agent1.post_xfer_req(&req, None).unwrap();
+        let start = std::time::Instant::now();
+        agent1
+            .wait_with_logging(&req, interval, std::time::Duration::from_secs(30))
+            .unwrap();
+        start.elapsed()
+    });
+
+    // One progress event per elapsed interval at most, plus the completion
+    let events = events.load(std::sync::atomic::Ordering::SeqCst);
+    let intervals = (waited.as_micros() / interval.as_micros()) as usize;
+    assert!(events >= 1);
+    assert!(events <= intervals + 1, "{events} events in {waited:?}");
+    if intervals >= 4 {
+        assert!(events >= 2, "No progress event in {waited:?}");
+    }
+    assert!(storage2.as_slice().iter().all(|&x| x == 0x5a));
+}
+
+#[test]
+fn test_wait_xfer() {
+    let agent1 = Agent::new("wait_xfer_A1").unwrap();
+    let agent2 = Agent::new("wait_xfer_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(1024 * 1024).unwrap();
+    let mut storage2 = SystemStorage::new(1024 * 1024).unwrap();
+    storage1.memset(0x4d);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+    assert!(agent1
+        .wait_xfer(&req, std::time::Duration::from_secs(30))
+        .unwrap());
+    assert!(storage2.as_slice().iter().all(|&x| x == 0x4d));
+
+    // A completed transfer is reported even with no time left
+    assert!(agent1.wait_xfer(&req, std::time::Duration::ZERO).unwrap());
+
+    // An unrepresentable deadline waits without one
+    agent1.post_xfer_req(&req, None).unwrap();
+    assert!(agent1.wait_xfer(&req, std::time::Duration::MAX).unwrap());
+}
+
+/// Wakes the thread blocked in `block_on`
+struct ThreadWaker(std::thread::Thread);
+
+impl std::task::Wake for ThreadWaker {
+    fn wake(self: std::sync::Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Runs a future to completion on the current thread
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    let waker = std::sync::Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut cx = std::task::Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            std::task::Poll::Ready(output) => return output,
+            std::task::Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[test]
+fn test_xfer_completion_future() {
+    const SIZE: usize = 64 * 1024 * 1024;
+
+    let agent1 = Agent::new("xfer_future_A1").unwrap();
+    let agent2 = Agent::new("xfer_future_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(SIZE).unwrap();
+    let mut storage2 = SystemStorage::new(SIZE).unwrap();
+    let mut storage3 = SystemStorage::new(SIZE).unwrap();
+    storage1.memset(0x6b);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+    storage3.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote2 = XferDescList::new(MemType::Dram, false).unwrap();
+    remote2.add_storage_desc(&storage2).unwrap();
+    let mut remote3 = XferDescList::new(MemType::Dram, false).unwrap();
+    remote3.add_storage_desc(&storage3).unwrap();
+
+    let req2 = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote2, &remote_name, None)
+        .unwrap();
+    let req3 = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote3, &remote_name, None)
+        .unwrap();
+    agent1.post_xfer_req(&req2, None).unwrap();
+    agent1.post_xfer_req(&req3, None).unwrap();
+
+    // Dropping a pending future leaves the transfer running
+    drop(agent1.xfer_completion(&req3));
+
+    block_on(agent1.xfer_completion(&req2)).unwrap();
+    assert!(storage2.as_slice().iter().all(|&x| x == 0x6b));
+
+    block_on(agent1.xfer_completion(&req3)).unwrap();
+    assert!(storage3.as_slice().iter().all(|&x| x == 0x6b));
+}
+
+#[test]
+fn test_concurrent_posts() {
+    const THREADS: usize = 4;
+    const SIZE: usize = 1024 * 1024;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Agent>();
+
+    let agent1 = Agent::new("concurrent_A1").unwrap();
+    let agent2 = Agent::new("concurrent_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(SIZE).unwrap();
+    storage1.memset(0x2e);
+    storage1.register(&agent1, None).unwrap();
+    let mut targets: Vec<SystemStorage> = (0..THREADS)
+        .map(|_| SystemStorage::new(SIZE).unwrap())
+        .collect();
+    for target in targets.iter_mut() {
+        target.register(&agent2, None).unwrap();
+    }
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    std::thread::scope(|scope| {
+        for target in &targets {
+            let (agent, source, remote_name) = (&agent1, &storage1, &remote_name);
+            scope.spawn(move || {
+                let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+                local.add_storage_desc(source).unwrap();
+                let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+                remote.add_storage_desc(target).unwrap();
+
+                let req = agent
+                    .create_xfer_req(XferOp::Write, &local, &remote, remote_name, None)
+                    .unwrap();
+                agent.post_xfer_req(&req, None).unwrap();
+                assert!(agent
+                    .wait_xfer(&req, std::time::Duration::from_secs(30))
+                    .unwrap());
+            });
+        }
+    });
+
+    for target in &targets {
+        assert!(target.as_slice().iter().all(|&x| x == 0x2e));
+    }
+}
+
+#[test]
+fn test_post_batch() {
+    const COUNT: usize = 100;
+    const CHUNK: usize = 1024;
+
+    let agent1 = Agent::new("batch_A1").unwrap();
+    let agent2 = Agent::new("batch_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(COUNT * CHUNK).unwrap();
+    let mut storage2 = SystemStorage::new(2 * COUNT * CHUNK).unwrap();
+    storage1.memset(0x19);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+    let src = storage1.as_slice().as_ptr() as usize;
+    let dst = storage2.as_slice().as_ptr() as usize;
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    // The first half of the target is written as a batch, the second half one by one
+    let reqs: Vec<XferRequest> = (0..2 * COUNT)
+        .map(|i| {
+            let source = (src + (i % COUNT) * CHUNK, CHUNK, 0);
+            let local = XferDescList::from_descs(MemType::Dram, false, &[source]).unwrap();
+            let target = (dst + i * CHUNK, CHUNK, 0);
+            let remote = XferDescList::from_descs(MemType::Dram, false, &[target]).unwrap();
+            agent1
+                .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+                .unwrap()
+        })
+        .collect();
+    let (batched, individual) = reqs.split_at(COUNT);
+
+    let start = std::time::Instant::now();
+    let batch: Vec<&XferRequest> = batched.iter().collect();
+    let mut handle = agent1.post_batch(&batch, None).unwrap();
+    let batch_time = start.elapsed();
+    assert_eq!(handle.len(), COUNT);
+
+    let start = std::time::Instant::now();
+    for req in individual {
+        agent1.post_xfer_req(req, None).unwrap();
+    }
+    let individual_time = start.elapsed();
+    println!("Submitted {COUNT} transfers: batch {batch_time:?}, individually {individual_time:?}");
+
+    handle.wait_all().unwrap();
+    assert!(handle.poll().unwrap());
+    for req in individual {
+        assert!(agent1
+            .wait_xfer(req, std::time::Duration::from_secs(30))
+            .unwrap());
+    }
+    assert!(storage2.as_slice().iter().all(|&x| x == 0x19));
+}
+
+#[test]
+fn test_post_xfer_req_with_progress() {
+    const CHUNK: usize = 1024;
+    const CHUNKS: usize = 4;
+
+    let agent1 = Agent::new("progress_A1").unwrap();
+    let agent2 = Agent::new("progress_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut source = SystemStorage::new(CHUNK * CHUNKS).unwrap();
+    let mut dest = SystemStorage::new(CHUNK * CHUNKS).unwrap();
+    source.fill_with(|i| (i % 251) as u8);
+    source.register(&agent1, None).unwrap();
+    dest.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    let source_addr = unsafe { source.as_ptr() } as usize;
+    let dest_addr = unsafe { dest.as_ptr() } as usize;
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    for chunk in 0..CHUNKS {
+        local
+            .add_desc(source_addr + chunk * CHUNK, CHUNK, 0)
+            .unwrap();
+        remote
+            .add_desc(dest_addr + chunk * CHUNK, CHUNK, 0)
+            .unwrap();
+    }
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, None)
+        .unwrap();
+
+    let mut args = OptArgs::new().unwrap();
+    args.set_has_notification(true).unwrap();
+    args.set_notification_message(b"progress").unwrap();
+
+    let mut reports = Vec::new();
+    agent1
+        .post_xfer_req_with_progress(&req, Some(&args), |done, total| reports.push((done, total)))
+        .unwrap();
+
+    assert_eq!(reports.first(), Some(&(0, CHUNK * CHUNKS)));
+    assert_eq!(reports.last(), Some(&(CHUNK * CHUNKS, CHUNK * CHUNKS)));
+    assert!(reports.len() <= CHUNKS + 1);
+    assert!(reports.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert!(reports.iter().all(|(done, _)| done % CHUNK == 0));
+    assert_eq!(dest.as_slice(), source.as_slice());
+
+    let mut notifs = NotificationMap::new().unwrap();
+    let start = std::time::Instant::now();
+    while notifs.is_empty().unwrap() && start.elapsed() < std::time::Duration::from_secs(10) {
+        agent2.get_notifications(&mut notifs, None).unwrap();
+    }
+    let messages = notifs.take_notifs().unwrap();
+    assert_eq!(messages["progress_A1"], vec!["progress".to_string()]);
+}
+
+#[test]
+fn test_post_xfer_req_with_progress_keeps_creation_args() {
+    const CHUNK: usize = 512;
+    const CHUNKS: usize = 3;
+
+    let agent1 = Agent::new("progress_args_A1").unwrap();
+    let agent2 = Agent::new("progress_args_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut source = SystemStorage::new(CHUNK * CHUNKS).unwrap();
+    let mut dest = SystemStorage::new(CHUNK * CHUNKS).unwrap();
+    source.fill_with(|i| (i % 253) as u8);
+    source.register(&agent1, None).unwrap();
+    dest.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    let source_addr = unsafe { source.as_ptr() } as usize;
+    let dest_addr = unsafe { dest.as_ptr() } as usize;
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    for chunk in 0..CHUNKS {
+        local
+            .add_desc(source_addr + chunk * CHUNK, CHUNK, 0)
+            .unwrap();
+        remote
+            .add_desc(dest_addr + chunk * CHUNK, CHUNK, 0)
+            .unwrap();
+    }
+
+    let mut args = OptArgs::new().unwrap();
+    args.add_backend(&backend1).unwrap();
+    args.set_has_notification(true).unwrap();
+    args.set_notification_message(b"created").unwrap();
+    args.set_notify_with_length(true);
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local, &remote, &remote_name, Some(&args))
+        .unwrap();
+
+    agent1
+        .post_xfer_req_with_progress(&req, None, |_, _| {})
+        .unwrap();
+    assert_eq!(dest.as_slice(), source.as_slice());
+
+    let mut notifs = NotificationMap::new().unwrap();
+    let start = std::time::Instant::now();
+    while notifs.is_empty().unwrap() && start.elapsed() < std::time::Duration::from_secs(10) {
+        agent2.get_notifications(&mut notifs, None).unwrap();
+    }
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    agent2.get_notifications(&mut notifs, None).unwrap();
+
+    let mut expected = ((CHUNK * CHUNKS) as u64).to_le_bytes().to_vec();
+    expected.extend_from_slice(b"created");
+    let messages = notifs.drain_sender("progress_args_A1").unwrap();
+    assert_eq!(messages, Some(vec![expected]));
+}
+
+#[test]
+fn test_preload_plugins() {
+    let plugins = preload_plugins().unwrap();
+    assert!(plugins.iter().any(|name| name == "UCX"));
+    assert_eq!(preload_plugins().unwrap(), plugins);
+
+    for name in ["preload_A1", "preload_A2"] {
+        let agent = Agent::new(name).unwrap();
+        let available: Vec<String> = agent
+            .get_available_plugins()
+            .unwrap()
+            .iter()
+            .map(|name| name.unwrap().to_string())
+            .collect();
+        assert_eq!(available, plugins);
+    }
+}
+
+#[test]
+fn test_transfer() {
+    let agent1 = Agent::new("transfer_A1").unwrap();
+    let agent2 = Agent::new("transfer_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(4096).unwrap();
+    let mut storage2 = SystemStorage::new(4096).unwrap();
+    storage1.memset(0x61);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    {
+        let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+        local.add_storage_desc(&storage1).unwrap();
+        let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+        remote.add_storage_desc(&storage2).unwrap();
+        agent1
+            .transfer(XferOp::Write, &local, &remote, &remote_name, Some(b"done"))
+            .unwrap();
+    }
+    assert!(storage2.as_slice().iter().all(|&x| x == 0x61));
+
+    let mut notifs = NotificationMap::new().unwrap();
+    let start = std::time::Instant::now();
+    while notifs.is_empty().unwrap() && start.elapsed() < std::time::Duration::from_secs(10) {
+        agent2.get_notifications(&mut notifs, None).unwrap();
+    }
+    let messages = notifs.take_notifs().unwrap();
+    assert_eq!(messages["transfer_A1"], vec!["done".to_string()]);
+}
+
+#[test]
+fn test_post_owned() {
+    let agent1 = Agent::new("post_owned_A1").unwrap();
+    let agent2 = Agent::new("post_owned_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(4096).unwrap();
+    let mut storage2 = SystemStorage::new(4096).unwrap();
+    storage1.memset(0x3c);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let future = agent1.post_owned(XferOp::Write, local, remote, &remote_name, None);
+    let (mut local, mut remote) = block_on(future).unwrap();
+    assert_eq!(local.len().unwrap(), 1);
+    assert_eq!(remote.len().unwrap(), 1);
+
+    // The recovered lists can be reused for the next transfer
+    local.clear().unwrap();
+    remote.clear().unwrap();
+    assert!(local.is_empty().unwrap());
+
+    // Errors are reported through the future
+    let future = agent1.post_owned(XferOp::Write, local, remote, "unknown_remote", None);
+    assert!(block_on(future).is_err());
+}
+
 #[test]
 fn test_etcd_metadata_exchange() -> Result<(), NixlError> {
     // Check if NIXL_ETCD_ENDPOINTS env var is set to skip test if not
@@ -577,6 +3829,45 @@ fn test_etcd_metadata_exchange() -> Result<(), NixlError> {
     Ok(())
 }
 
+#[test]
+fn test_notification_peek_and_drain() -> Result<(), NixlError> {
+    let sender1 = Agent::new("PeekSender1")?;
+    let sender2 = Agent::new("PeekSender2")?;
+    let receiver = Agent::new("PeekReceiver")?;
+
+    let (_mem_list, params) = receiver.get_plugin_params("UCX")?;
+    let _backend1 = sender1.create_backend("UCX", &params)?;
+    let _backend2 = sender2.create_backend("UCX", &params)?;
+    let _backend3 = receiver.create_backend("UCX", &params)?;
+
+    let metadata = receiver.get_local_md()?;
+    sender1.load_remote_md(&metadata)?;
+    sender2.load_remote_md(&metadata)?;
+
+    sender1.send_notification("PeekReceiver", b"from sender1", None)?;
+    sender2.send_notification("PeekReceiver", b"from sender2", None)?;
+
+    let mut notifs = NotificationMap::new()?;
+    while !(notifs.contains("PeekSender1")? && notifs.contains("PeekSender2")?) {
+        receiver.get_notifications(&mut notifs, None)?;
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    let peeked = notifs.peek("PeekSender1")?.unwrap();
+    assert_eq!(peeked, vec![&b"from sender1"[..]]);
+
+    let drained = notifs.drain_sender("PeekSender2")?.unwrap();
+    assert_eq!(drained, vec![b"from sender2".to_vec()]);
+
+    assert!(notifs.contains("PeekSender1")?);
+    assert!(!notifs.contains("PeekSender2")?);
+    assert_eq!(notifs.len()?, 1);
+    assert!(notifs.peek("PeekSender2")?.is_none());
+    assert!(notifs.drain_sender("PeekSender2")?.is_none());
+
+    Ok(())
+}
+
 #[test]
 fn test_send_notification() -> Result<(), NixlError> {
     // Create two agents for notification exchange
@@ -637,6 +3928,432 @@ fn test_send_notification() -> Result<(), NixlError> {
     Ok(())
 }
 
+#[test]
+fn test_count_notifications() {
+    let agent1 = Agent::new("CountSender").unwrap();
+    let agent2 = Agent::new("CountReceiver").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+    agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    for message in [b"one", b"two", b"six"] {
+        agent1
+            .send_notification("CountReceiver", message, None)
+            .unwrap();
+    }
+
+    // Notifications arrive asynchronously, so count until all three are in
+    let mut count = 0;
+    let start = std::time::Instant::now();
+    while count < 3 && start.elapsed() < std::time::Duration::from_secs(10) {
+        count += agent2.count_notifications().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    assert_eq!(count, 3);
+
+    assert_eq!(agent2.count_notifications().unwrap(), 0);
+    let mut notifs = NotificationMap::new().unwrap();
+    agent2.get_notifications(&mut notifs, None).unwrap();
+    assert!(notifs.is_empty().unwrap());
+}
+
+#[test]
+fn test_notification_stream() {
+    let agent1 = Agent::new("StreamSender").unwrap();
+    let agent2 = Agent::new("StreamReceiver").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+    agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut stream = agent2.notification_stream();
+    agent1
+        .send_notification("StreamReceiver", b"first", None)
+        .unwrap();
+    agent1
+        .send_notification("StreamReceiver", b"second", None)
+        .unwrap();
+
+    let mut received = Vec::new();
+    while received.len() < 2 {
+        let (from, msg) = block_on(stream.next()).unwrap().unwrap();
+        assert_eq!(from, "StreamSender");
+        received.push(msg);
+    }
+    assert_eq!(received, vec![b"first".to_vec(), b"second".to_vec()]);
+
+    // A notification arriving after the stream is dropped is kept for the next one
+    drop(stream);
+    agent1
+        .send_notification("StreamReceiver", b"third", None)
+        .unwrap();
+    let (_, msg) = block_on(agent2.notification_stream().next())
+        .unwrap()
+        .unwrap();
+    assert_eq!(msg, b"third");
+}
+
+#[test]
+fn test_notify_with_length() {
+    let agent1 = Agent::new("notify_len_A1").unwrap();
+    let agent2 = Agent::new("notify_len_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let local_addr = storage1.as_slice().as_ptr() as usize;
+    let remote_addr = storage2.as_slice().as_ptr() as usize;
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_desc(local_addr, 100, 0).unwrap();
+    local.add_desc(local_addr + 128, 50, 0).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_desc(remote_addr, 100, 0).unwrap();
+    remote.add_desc(remote_addr + 128, 50, 0).unwrap();
+
+    let mut xfer_args = OptArgs::new().unwrap();
+    xfer_args.set_notification_message(b"tail").unwrap();
+    xfer_args.set_notify_with_length(true);
+    assert!(xfer_args.notify_with_length());
+
+    let req = agent1
+        .create_xfer_req(
+            XferOp::Write,
+            &local,
+            &remote,
+            &remote_name,
+            Some(&xfer_args),
+        )
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+    while agent1.get_xfer_status(&req).unwrap() {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+
+    let mut notifs = NotificationMap::new().unwrap();
+    let start = std::time::Instant::now();
+    while !notifs.contains("notify_len_A1").unwrap() {
+        assert!(
+            start.elapsed().as_secs() < 5,
+            "Timed out waiting for notification"
+        );
+        agent2.get_notifications(&mut notifs, None).unwrap();
+    }
+
+    let messages = notifs.drain_sender("notify_len_A1").unwrap().unwrap();
+    assert_eq!(messages.len(), 1);
+    let (length, rest) = messages[0].split_at(8);
+    assert_eq!(u64::from_le_bytes(length.try_into().unwrap()), 150);
+    assert_eq!(rest, b"tail");
+}
+
+#[test]
+fn test_notify_with_sequence() {
+    let agent1 = Agent::new("notify_seq_A1").unwrap();
+    let agent2 = Agent::new("notify_seq_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let messages: [&[u8]; 3] = [b"first", b"second", b"third"];
+    for message in messages {
+        let mut xfer_args = OptArgs::new().unwrap();
+        xfer_args.set_notification_message(message).unwrap();
+        xfer_args.set_notify_with_sequence(true);
+        let req = agent1
+            .create_xfer_req(
+                XferOp::Write,
+                &local,
+                &remote,
+                &remote_name,
+                Some(&xfer_args),
+            )
+            .unwrap();
+        agent1.post_xfer_req(&req, None).unwrap();
+        while agent1.get_xfer_status(&req).unwrap() {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+    }
+
+    let mut notifs = NotificationMap::new().unwrap();
+    let start = std::time::Instant::now();
+    while notifs.get_notifications_size("notify_seq_A1").unwrap_or(0) < 3 {
+        assert!(
+            start.elapsed().as_secs() < 5,
+            "Timed out waiting for notifications"
+        );
+        agent2.get_notifications(&mut notifs, None).unwrap();
+    }
+
+    // Arrival order matches post order on a single connection
+    let arrived = notifs.peek("notify_seq_A1").unwrap().unwrap();
+    for (message, expected) in arrived.iter().zip(messages) {
+        assert_eq!(&message[8..], expected);
+    }
+
+    let ordered = notifs
+        .drain_sender_ordered("notify_seq_A1")
+        .unwrap()
+        .unwrap();
+    assert_eq!(ordered.len(), 3);
+    for (index, (seq, message)) in ordered.iter().enumerate() {
+        assert_eq!(*seq, index as u64);
+        assert_eq!(message.as_slice(), messages[index]);
+    }
+    assert!(!notifs.contains("notify_seq_A1").unwrap());
+}
+
+#[test]
+fn test_notify_with_sequence_assigned_at_post() {
+    let agent1 = Agent::new("notify_seq_post_A1").unwrap();
+    let agent2 = Agent::new("notify_seq_post_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let create = |message: &[u8]| {
+        let mut xfer_args = OptArgs::new().unwrap();
+        xfer_args.set_notification_message(message).unwrap();
+        xfer_args.set_notify_with_sequence(true);
+        agent1
+            .create_xfer_req(
+                XferOp::Write,
+                &local,
+                &remote,
+                &remote_name,
+                Some(&xfer_args),
+            )
+            .unwrap()
+    };
+    let first = create(b"first");
+    let second = create(b"second");
+    let report = agent1.dry_run_xfer(XferOp::Write, &local, &remote, &remote_name, None);
+    assert!(report.unwrap().problems.is_empty());
+
+    // Posted out of creation order, and the second request twice
+    for req in [&second, &first, &second] {
+        agent1.post_xfer_req(req, None).unwrap();
+        while agent1.get_xfer_status(req).unwrap() {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+    }
+
+    let mut notifs = NotificationMap::new().unwrap();
+    let start = std::time::Instant::now();
+    while notifs
+        .get_notifications_size("notify_seq_post_A1")
+        .unwrap_or(0)
+        < 3
+    {
+        assert!(
+            start.elapsed().as_secs() < 5,
+            "Timed out waiting for notifications"
+        );
+        agent2.get_notifications(&mut notifs, None).unwrap();
+    }
+
+    let ordered = notifs
+        .drain_sender_ordered("notify_seq_post_A1")
+        .unwrap()
+        .unwrap();
+    let expected: [(u64, &[u8]); 3] = [(0, b"second"), (1, b"first"), (2, b"second")];
+    assert_eq!(ordered.len(), 3);
+    for ((seq, message), (expected_seq, expected_message)) in ordered.iter().zip(expected) {
+        assert_eq!(*seq, expected_seq);
+        assert_eq!(message.as_slice(), expected_message);
+    }
+}
+
+#[test]
+fn test_flush_notifications() {
+    let agent1 = Agent::new("flush_A1").unwrap();
+    let agent2 = Agent::new("flush_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(256).unwrap();
+    let mut storage2 = SystemStorage::new(256).unwrap();
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    assert!(matches!(
+        agent1.flush_notifications(Some("unknown_remote")),
+        Err(NixlError::InvalidParam)
+    ));
+
+    let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+    local.add_storage_desc(&storage1).unwrap();
+    let mut remote = XferDescList::new(MemType::Dram, false).unwrap();
+    remote.add_storage_desc(&storage2).unwrap();
+
+    let mut xfer_args = OptArgs::new().unwrap();
+    xfer_args.set_has_notification(true).unwrap();
+    xfer_args.set_notification_message(b"flushed").unwrap();
+
+    let req = agent1
+        .create_xfer_req(
+            XferOp::Write,
+            &local,
+            &remote,
+            &remote_name,
+            Some(&xfer_args),
+        )
+        .unwrap();
+    let mut in_progress = agent1.post_xfer_req(&req, None).unwrap();
+    while in_progress {
+        in_progress = agent1.get_xfer_status(&req).unwrap();
+    }
+    agent1.flush_notifications(Some(&remote_name)).unwrap();
+
+    // The receiver flushes too; what it receives is kept for get_notifications
+    let mut notifs = NotificationMap::new().unwrap();
+    let start = std::time::Instant::now();
+    while notifs.is_empty().unwrap() {
+        assert!(
+            start.elapsed().as_secs() < 5,
+            "Timed out waiting for notification"
+        );
+        agent2.flush_notifications(None).unwrap();
+        agent2.get_notifications(&mut notifs, None).unwrap();
+    }
+
+    let notify_map = notifs.take_notifs().unwrap();
+    assert_eq!(
+        notify_map.get("flush_A1").unwrap(),
+        &vec!["flushed".to_string()]
+    );
+}
+
+#[test]
+fn test_get_notifications_limited() {
+    let agent1 = Agent::new("limited_A1").unwrap();
+    let agent2 = Agent::new("limited_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    for i in 0..10 {
+        let message = format!("notif-{i}");
+        agent1
+            .send_notification(&remote_name, message.as_bytes(), None)
+            .unwrap();
+    }
+
+    let mut received = Vec::new();
+    let start = std::time::Instant::now();
+    while received.len() < 10 {
+        assert!(
+            start.elapsed().as_secs() < 5,
+            "Timed out waiting for notifications"
+        );
+        let mut notifs = NotificationMap::new().unwrap();
+        agent2.get_notifications_limited(3, &mut notifs).unwrap();
+
+        let batch = notifs
+            .drain_sender("limited_A1")
+            .unwrap()
+            .unwrap_or_default();
+        assert!(batch.len() <= 3);
+        received.extend(batch);
+    }
+
+    let expected: Vec<Vec<u8>> = (0..10).map(|i| format!("notif-{i}").into_bytes()).collect();
+    assert_eq!(received, expected);
+}
+
+#[test]
+fn test_notifications_timestamped() {
+    let agent1 = Agent::new("timestamp_A1").unwrap();
+    let agent2 = Agent::new("timestamp_A2").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let remote_name = agent1
+        .load_remote_md(&agent2.get_local_md().unwrap())
+        .unwrap();
+    let mut notifs = NotificationMap::new().unwrap();
+
+    for (sent, message) in [&b"first"[..], &b"second"[..]].into_iter().enumerate() {
+        agent1
+            .send_notification(&remote_name, message, None)
+            .unwrap();
+
+        let start = std::time::Instant::now();
+        while notifs.entries_timestamped().unwrap().len() <= sent {
+            assert!(
+                start.elapsed().as_secs() < 5,
+                "Timed out waiting for notification"
+            );
+            agent2.get_notifications(&mut notifs, None).unwrap();
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    let entries = notifs.entries_timestamped().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].1, "timestamp_A1");
+    assert_eq!(entries[0].2, b"first");
+    assert_eq!(entries[1].2, b"second");
+    assert!(entries[0].0 < entries[1].0);
+}
+
 #[test]
 fn test_check_remote_metadata() {
     // Create two agents
@@ -912,6 +4629,26 @@ fn test_reg_desc_list_verify_sorted_false() {
     assert!(!dlist.verify_sorted().unwrap());
 }
 
+#[test]
+fn test_reg_desc_list_sort_by_addr() {
+    let mut dlist = RegDescList::new(MemType::Dram, false).unwrap();
+    dlist.add_desc_with_meta(0x3000, 0x100, 0, b"c").unwrap();
+    dlist.add_desc_with_meta(0x1000, 0x100, 0, b"a").unwrap();
+    dlist.add_desc_with_meta(0x2000, 0x100, 0, b"b").unwrap();
+    assert!(!dlist.is_sorted().unwrap());
+    assert!(!dlist.verify_sorted().unwrap());
+
+    dlist.sort_by_addr().unwrap();
+    assert!(dlist.is_sorted().unwrap());
+    assert!(dlist.verify_sorted().unwrap());
+    assert!(!dlist.has_overlaps().unwrap());
+
+    let first = dlist.get(0).unwrap();
+    assert_eq!(first.addr, 0x1000);
+    assert_eq!(first.meta, Some(b"a".to_vec()));
+    assert_eq!(dlist.get(2).unwrap().addr, 0x3000);
+}
+
 #[test]
 fn test_reg_desc_list_desc_count_basic() {
     let mut dlist = RegDescList::new(MemType::Dram, false).unwrap();
@@ -1013,6 +4750,49 @@ fn test_reg_desc_list_print_after_add() {
     assert!(dlist.print().is_ok());
 }
 
+#[test]
+fn test_reg_dlist_add_file_desc() {
+    let mut descs = RegDescList::new(MemType::File, false).unwrap();
+    let path = std::path::Path::new("/tmp/nixl_shard_0.bin");
+    assert_eq!(descs.add_file_desc(0, 4096, 0, path).unwrap(), 0);
+    assert_eq!(
+        descs.get(0).unwrap().meta.as_deref(),
+        Some(&b"/tmp/nixl_shard_0.bin"[..])
+    );
+
+    let result = descs.add_file_desc(0, 4096, 0, std::path::Path::new("/tmp/bad\0path"));
+    assert!(matches!(result, Err(NixlError::StringConversionError(_))));
+    assert_eq!(descs.len().unwrap(), 1);
+}
+
+#[test]
+fn test_reg_dlist_add_directory() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let nested = temp_dir.path().join("nested");
+    std::fs::create_dir(&nested).unwrap();
+    std::fs::write(temp_dir.path().join("shard_0.bin"), [0u8; 16]).unwrap();
+    std::fs::write(temp_dir.path().join("shard_1.bin"), [1u8; 32]).unwrap();
+    std::fs::write(nested.join("shard_2.bin"), [2u8; 64]).unwrap();
+
+    let mut flat = RegDescList::new(MemType::File, false).unwrap();
+    assert_eq!(flat.add_directory(temp_dir.path(), false).unwrap(), 2);
+
+    let mut descs = RegDescList::new(MemType::File, false).unwrap();
+    assert_eq!(descs.add_directory(temp_dir.path(), true).unwrap(), 3);
+    assert_eq!(descs.len().unwrap(), 3);
+    let lens: Vec<usize> = descs.iter().map(|desc| desc.unwrap().len).collect();
+    assert_eq!(lens, vec![64, 16, 32]);
+
+    let agent = Agent::new("directory_agent").unwrap();
+    let (_backend, opt_args) = match create_posix_backend(&agent) {
+        Some(result) => result,
+        None => return,
+    };
+    let resp = agent.query_mem(&descs, Some(&opt_args)).unwrap();
+    assert_eq!(resp.len().unwrap(), 3);
+    assert!(resp.iter().unwrap().all(|r| r.has_value().unwrap()));
+}
+
 #[test]
 fn test_query_mem_with_files() {
     use std::fs::File;
@@ -1123,6 +4903,146 @@ fn test_query_mem_with_files() {
     }
 }
 
+#[test]
+fn test_posix_file_info() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let existing = temp_dir.path().join("posix_file_info.txt");
+    std::fs::write(&existing, b"posix file info").unwrap();
+    let missing = temp_dir.path().join("posix_file_info_missing.txt");
+
+    let agent = Agent::new("posix_file_info_agent").unwrap();
+    let (_backend, opt_args) = match create_posix_backend(&agent) {
+        Some(result) => result,
+        None => return,
+    };
+
+    let mut descs = RegDescList::new(MemType::File, false).unwrap();
+    for path in [&existing, &missing] {
+        descs
+            .add_desc_with_meta(0, 1024, 0, path.to_string_lossy().as_bytes())
+            .unwrap();
+    }
+    let resp = agent.query_mem(&descs, Some(&opt_args)).unwrap();
+    let responses: Vec<_> = resp.iter().unwrap().collect();
+    assert_eq!(responses.len(), 2);
+
+    let info = responses[0]
+        .as_posix_file_info()
+        .expect("Missing POSIX file info");
+    assert_eq!(info.mode & 0o170000, 0o100000, "Expected a regular file");
+    assert!(info.modified > std::time::SystemTime::UNIX_EPOCH);
+    if let Some(size) = info.size {
+        assert_eq!(size, 15);
+    }
+    assert!(responses[1].as_posix_file_info().is_none());
+}
+
+#[test]
+fn test_query_mem_results() {
+    use std::os::unix::fs::PermissionsExt;
+    use std::time::{Duration, SystemTime};
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let existing = temp_dir.path().join("query_results.txt");
+    std::fs::write(&existing, b"query results").unwrap();
+    let missing = temp_dir.path().join("query_results_missing.txt");
+
+    let agent = Agent::new("query_results_agent").unwrap();
+    let (_backend, opt_args) = match create_posix_backend(&agent) {
+        Some(result) => result,
+        None => return,
+    };
+
+    let mut descs = RegDescList::new(MemType::File, false).unwrap();
+    for path in [&existing, &missing] {
+        descs
+            .add_desc_with_meta(0, 1024, 0, path.to_string_lossy().as_bytes())
+            .unwrap();
+    }
+
+    let results = agent
+        .query_mem(&descs, Some(&opt_args))
+        .unwrap()
+        .into_vec()
+        .unwrap();
+    assert_eq!(results.len(), 2);
+
+    let metadata = std::fs::metadata(&existing).unwrap();
+    assert!(results[0].exists);
+    assert_eq!(results[0].size, Some(metadata.len()));
+    assert_eq!(results[0].mode, Some(metadata.permissions().mode()));
+    let mtime = results[0].mtime.unwrap();
+    let modified = metadata.modified().unwrap();
+    assert!(modified.duration_since(mtime).unwrap_or_default() < Duration::from_secs(1));
+    assert!(mtime <= SystemTime::now());
+
+    assert!(!results[1].exists);
+    assert_eq!(results[1].size, None);
+    assert_eq!(results[1].mtime, None);
+    assert_eq!(results[1].mode, None);
+    assert!(results[1].raw.is_empty());
+}
+
+#[test]
+fn test_file_storage() {
+    const SIZE: usize = 512;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("file_storage.bin");
+    std::fs::write(&path, vec![0u8; SIZE]).unwrap();
+
+    assert!(matches!(
+        FileStorage::open(&temp_dir.path().join("missing.bin")),
+        Err(NixlError::Io(std::io::ErrorKind::NotFound))
+    ));
+
+    let agent = Agent::new("file_storage_agent").unwrap();
+    let (_backend, opt_args) = match create_posix_backend(&agent) {
+        Some(result) => result,
+        None => return,
+    };
+
+    let mut file = FileStorage::open(&path).unwrap();
+    assert_eq!(file.size(), SIZE);
+    assert_eq!(file.path(), path.as_path());
+    assert_eq!(file.mem_type(), MemType::File);
+    assert_eq!(file.device_id(), file.fd() as u64);
+    file.register(&agent, Some(&opt_args)).unwrap();
+
+    let mut storage = SystemStorage::new(SIZE).unwrap();
+    storage.memset(0x7e);
+    storage.register(&agent, Some(&opt_args)).unwrap();
+
+    {
+        let mut reg_descs = RegDescList::new(MemType::File, false).unwrap();
+        reg_descs.add_storage_desc(&file).unwrap();
+        assert_eq!(reg_descs.len().unwrap(), 1);
+
+        let mut local = XferDescList::new(MemType::Dram, false).unwrap();
+        local.add_storage_desc(&storage).unwrap();
+        let mut remote = XferDescList::new(MemType::File, false).unwrap();
+        remote.add_storage_desc(&file).unwrap();
+        let (addr, len, dev_id) = remote.get_desc(0).unwrap();
+        assert_eq!((addr, len, dev_id), (0, SIZE, file.fd() as u64));
+
+        let req = agent
+            .create_xfer_req(
+                XferOp::Write,
+                &local,
+                &remote,
+                &agent.name(),
+                Some(&opt_args),
+            )
+            .unwrap();
+        if agent.post_xfer_req(&req, Some(&opt_args)).unwrap() {
+            while agent.get_xfer_status(&req).unwrap() {
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+        }
+    }
+    assert!(std::fs::read(&path).unwrap().iter().all(|&b| b == 0x7e));
+}
+
 #[test]
 fn test_query_mem_empty_list() {
     // Constants
@@ -19,6 +19,7 @@
 #include "nixl.h"
 #include "nixl_types.h"
 
+#include <algorithm>
 #include <cstdlib>
 #include <cstring>
 #include <exception>
@@ -92,7 +93,30 @@ nixl_capi_create_agent(const char* name, nixl_capi_agent_t* agent)
   }
 
   try {
-    nixlAgentConfig nixl_config(true);  // Use progress thread
+    // Use a progress thread, and let NIXL lock internally so the agent can be shared across threads
+    nixlAgentConfig nixl_config(true, false, 0, nixl_thread_sync_t::NIXL_THREAD_SYNC_RW);
+    std::string agent_name = name;
+    auto inner = new nixlAgent(agent_name, nixl_config);
+
+    auto agent_handle = new nixl_capi_agent_s;
+    agent_handle->inner = inner;
+    *agent = agent_handle;
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
+nixl_capi_status_t
+nixl_capi_create_agent_with_listener(const char* name, int port, nixl_capi_agent_t* agent)
+{
+  if (!name || !agent || port <= 0) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    nixlAgentConfig nixl_config(true, true, port, nixl_thread_sync_t::NIXL_THREAD_SYNC_RW);
     std::string agent_name = name;
     auto inner = new nixlAgent(agent_name, nixl_config);
 
@@ -278,6 +302,38 @@ nixl_capi_check_remote_md(nixl_capi_agent_t agent, const char* remote_name, nixl
   }
 }
 
+nixl_capi_status_t
+nixl_capi_probe_remote_md(
+    nixl_capi_agent_t agent, const char* remote_name, nixl_capi_xfer_dlist_t descs, bool* all_found, size_t* missing_index)
+{
+  if (!agent || !remote_name || !descs || !all_found || !missing_index) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    // The remote metadata itself must be loaded
+    nixl_xfer_dlist_t empty_list(descs->dlist->getType(), true);
+    if (agent->inner->checkRemoteMD(remote_name, empty_list) != NIXL_SUCCESS) {
+      return NIXL_CAPI_ERROR_BACKEND;
+    }
+
+    *all_found = true;
+    for (int i = 0; i < descs->dlist->descCount(); ++i) {
+      nixl_xfer_dlist_t single(descs->dlist->getType(), true);
+      single.addDesc((*descs->dlist)[i]);
+      if (agent->inner->checkRemoteMD(remote_name, single) != NIXL_SUCCESS) {
+        *all_found = false;
+        *missing_index = i;
+        break;
+      }
+    }
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
 nixl_capi_status_t
 nixl_capi_get_available_plugins(nixl_capi_agent_t agent, nixl_capi_string_list_t* plugins)
 {
@@ -304,6 +360,33 @@ nixl_capi_get_available_plugins(nixl_capi_agent_t agent, nixl_capi_string_list_t
   }
 }
 
+nixl_capi_status_t
+nixl_capi_preload_plugins(nixl_capi_string_list_t* plugins)
+{
+  if (!plugins) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    // Discovery is process-wide, so a short-lived agent is enough to run it
+    nixlAgentConfig nixl_config(false);
+    nixlAgent agent("nixl_capi_preload", nixl_config);
+    std::vector<nixl_backend_t> backend_plugins;
+    if (agent.getAvailPlugins(backend_plugins) != NIXL_SUCCESS) {
+      return NIXL_CAPI_ERROR_BACKEND;
+    }
+
+    auto list = new nixl_capi_string_list_s;
+    list->strings = std::move(backend_plugins);
+    *plugins = list;
+
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
 nixl_capi_status_t
 nixl_capi_destroy_string_list(nixl_capi_string_list_t list)
 {
@@ -695,6 +778,38 @@ nixl_capi_params_destroy_iterator(nixl_capi_param_iter_t iter)
   }
 }
 
+nixl_capi_status_t
+nixl_capi_create_params(nixl_capi_params_t* params)
+{
+  if (!params) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    *params = new nixl_capi_params_s;
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
+nixl_capi_status_t
+nixl_capi_params_set(nixl_capi_params_t params, const char* key, const char* value)
+{
+  if (!params || !key || !value) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    params->params[key] = value;
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
 nixl_capi_status_t
 nixl_capi_mem_list_is_empty(nixl_capi_mem_list_t list, bool* is_empty)
 {
@@ -855,7 +970,7 @@ nixl_capi_xfer_dlist_get_type(nixl_capi_xfer_dlist_t dlist, nixl_capi_mem_type_t
 }
 
 nixl_capi_status_t
-nixl_capi_xfer_dlist_add_desc(nixl_capi_xfer_dlist_t dlist, uintptr_t addr, size_t len, uint64_t dev_id)
+nixl_capi_xfer_dlist_add_desc(nixl_capi_xfer_dlist_t dlist, uintptr_t addr, size_t len, uint64_t dev_id, size_t* index)
 {
   if (!dlist) {
     return NIXL_CAPI_ERROR_INVALID_PARAM;
@@ -863,7 +978,66 @@ nixl_capi_xfer_dlist_add_desc(nixl_capi_xfer_dlist_t dlist, uintptr_t addr, size
 
   try {
     nixlBasicDesc desc(addr, len, dev_id);
+    // Sorted lists insert at the upper bound, unsorted lists append
+    size_t pos = dlist->dlist->descCount();
+    if (dlist->dlist->isSorted()) {
+      pos = std::upper_bound(dlist->dlist->begin(), dlist->dlist->end(), desc) - dlist->dlist->begin();
+    }
     dlist->dlist->addDesc(desc);
+    if (index) {
+      *index = pos;
+    }
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
+nixl_capi_status_t
+nixl_capi_xfer_dlist_add_descs(
+    nixl_capi_xfer_dlist_t dlist, const uintptr_t* addrs, const size_t* lens, const uint64_t* dev_ids,
+    size_t count)
+{
+  if (!dlist || (count && (!addrs || !lens || !dev_ids))) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    auto& list = *dlist->dlist;
+    if (list.isSorted()) {
+      for (size_t i = 0; i < count; i++) {
+        list.addDesc(nixlBasicDesc(addrs[i], lens[i], dev_ids[i]));
+      }
+      return NIXL_CAPI_SUCCESS;
+    }
+
+    // Unsorted lists grow once and are filled in place
+    size_t start = list.descCount();
+    list.resize(start + count);
+    for (size_t i = 0; i < count; i++) {
+      list[start + i] = nixlBasicDesc(addrs[i], lens[i], dev_ids[i]);
+    }
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
+nixl_capi_status_t
+nixl_capi_xfer_dlist_get_desc(
+    nixl_capi_xfer_dlist_t dlist, size_t index, uintptr_t* addr, size_t* len, uint64_t* dev_id)
+{
+  if (!dlist || !addr || !len || !dev_id || index >= (size_t)dlist->dlist->descCount()) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    const nixlBasicDesc& desc = (*dlist->dlist)[index];
+    *addr = desc.addr;
+    *len = desc.len;
+    *dev_id = desc.devId;
     return NIXL_CAPI_SUCCESS;
   }
   catch (...) {
@@ -973,6 +1147,32 @@ nixl_capi_xfer_dlist_has_overlaps(nixl_capi_xfer_dlist_t dlist, bool* has_overla
   }
 }
 
+nixl_capi_status_t
+nixl_capi_reg_dlist_sort(nixl_capi_reg_dlist_t dlist)
+{
+  if (!dlist) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    std::vector<nixlBlobDesc> descs(dlist->dlist->begin(), dlist->dlist->end());
+    std::stable_sort(descs.begin(), descs.end(), [](const nixlBlobDesc& a, const nixlBlobDesc& b) {
+      return static_cast<const nixlBasicDesc&>(a) < static_cast<const nixlBasicDesc&>(b);
+    });
+
+    auto sorted = new nixl_reg_dlist_t(dlist->dlist->getType(), true);
+    for (const auto& desc : descs) {
+      sorted->addDesc(desc);
+    }
+    delete dlist->dlist;
+    dlist->dlist = sorted;
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
 nixl_capi_status_t
 nixl_capi_xfer_dlist_verify_sorted(nixl_capi_xfer_dlist_t dlist, bool* is_sorted)
 {
@@ -1036,6 +1236,60 @@ nixl_capi_xfer_dlist_resize(nixl_capi_xfer_dlist_t dlist, size_t new_size)
   }
 }
 
+nixl_capi_status_t
+nixl_capi_xfer_dlist_coalesce(nixl_capi_xfer_dlist_t dlist, size_t* merged)
+{
+  if (!dlist || !merged) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    auto& list = *dlist->dlist;
+    size_t count = 0;
+    int i = 0;
+    while (i + 1 < list.descCount()) {
+      nixlBasicDesc& cur = list[i];
+      const nixlBasicDesc& next = list[i + 1];
+      if (cur.devId == next.devId && cur.addr + cur.len == next.addr) {
+        cur.len += next.len;
+        list.remDesc(i + 1);
+        count++;
+      } else {
+        i++;
+      }
+    }
+    *merged = count;
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
+nixl_capi_status_t
+nixl_capi_xfer_dlist_sort(nixl_capi_xfer_dlist_t dlist)
+{
+  if (!dlist) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    std::vector<nixlBasicDesc> descs(dlist->dlist->begin(), dlist->dlist->end());
+    std::sort(descs.begin(), descs.end());
+
+    auto sorted = new nixl_xfer_dlist_t(dlist->dlist->getType(), true);
+    for (const auto& desc : descs) {
+      sorted->addDesc(desc);
+    }
+    delete dlist->dlist;
+    dlist->dlist = sorted;
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
 // Registration descriptor list functions
 nixl_capi_status_t
 nixl_capi_create_reg_dlist(nixl_capi_mem_type_t mem_type, nixl_capi_reg_dlist_t* dlist, bool sorted)
@@ -1110,7 +1364,8 @@ nixl_capi_reg_dlist_add_desc(nixl_capi_reg_dlist_t dlist,
                              size_t len,
                              uint64_t dev_id,
                              const void *metadata,
-                             size_t metadata_len) {
+                             size_t metadata_len,
+                             size_t *index) {
     if (!dlist) {
         return NIXL_CAPI_ERROR_INVALID_PARAM;
     }
@@ -1121,7 +1376,16 @@ nixl_capi_reg_dlist_add_desc(nixl_capi_reg_dlist_t dlist,
             meta_blob.assign((const char *)metadata, metadata_len);
         }
         nixlBlobDesc desc(addr, len, dev_id, meta_blob);
+        // Sorted lists insert at the upper bound, unsorted lists append
+        size_t pos = dlist->dlist->descCount();
+        if (dlist->dlist->isSorted()) {
+            pos = std::upper_bound(dlist->dlist->begin(), dlist->dlist->end(), desc) -
+                dlist->dlist->begin();
+        }
         dlist->dlist->addDesc(desc);
+        if (index) {
+            *index = pos;
+        }
 #ifdef NIXL_DEBUG
         printf("** Adding descriptor\n");
         dlist->dlist->print();
@@ -1134,6 +1398,34 @@ nixl_capi_reg_dlist_add_desc(nixl_capi_reg_dlist_t dlist,
     }
 }
 
+nixl_capi_status_t
+nixl_capi_reg_dlist_get_desc(nixl_capi_reg_dlist_t dlist,
+                             size_t index,
+                             uintptr_t *addr,
+                             size_t *len,
+                             uint64_t *dev_id,
+                             const void **metadata,
+                             size_t *metadata_len) {
+    if (!dlist || !addr || !len || !dev_id || !metadata || !metadata_len ||
+        index >= (size_t)dlist->dlist->descCount()) {
+        return NIXL_CAPI_ERROR_INVALID_PARAM;
+    }
+
+    try {
+        // The metadata stays owned by the list and is valid until it is modified
+        const nixlBlobDesc &desc = (*dlist->dlist)[index];
+        *addr = desc.addr;
+        *len = desc.len;
+        *dev_id = desc.devId;
+        *metadata = desc.metaInfo.data();
+        *metadata_len = desc.metaInfo.size();
+        return NIXL_CAPI_SUCCESS;
+    }
+    catch (...) {
+        return NIXL_CAPI_ERROR_BACKEND;
+    }
+}
+
 nixl_capi_status_t
 nixl_capi_reg_dlist_desc_count(nixl_capi_reg_dlist_t dlist, size_t* count)
 {
@@ -1428,6 +1720,28 @@ nixl_capi_get_xfer_status(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl
   }
 }
 
+nixl_capi_status_t
+nixl_capi_query_xfer_backend(
+    nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl, nixl_capi_backend_t backend, bool* selected)
+{
+  if (!agent || !req_hndl || !req_hndl->req || !backend || !selected) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    nixlBackendH* chosen = nullptr;
+    nixl_status_t ret = agent->inner->queryXferBackend(req_hndl->req, chosen);
+    if (ret != NIXL_SUCCESS) {
+      return NIXL_CAPI_ERROR_BACKEND;
+    }
+    *selected = (chosen == backend->backend);
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
 nixl_capi_status_t
 nixl_capi_destroy_xfer_req(nixl_capi_xfer_req_t req)
 {
@@ -1486,6 +1800,31 @@ nixl_capi_get_notifs(nixl_capi_agent_t agent, nixl_capi_notif_map_t notif_map, n
   }
 }
 
+nixl_capi_status_t
+nixl_capi_count_notifs(nixl_capi_agent_t agent, size_t* count)
+{
+  if (!agent || !count) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    nixl_notifs_t notifs;
+    nixl_status_t ret = agent->inner->getNotifs(notifs);
+    if (ret != NIXL_SUCCESS) {
+      return NIXL_CAPI_ERROR_BACKEND;
+    }
+
+    *count = 0;
+    for (const auto& [remote, messages] : notifs) {
+      *count += messages.size();
+    }
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (const std::exception& e) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
 nixl_capi_status_t
 nixl_capi_gen_notif(nixl_capi_agent_t agent, const char* remote_agent,
                    const void* data, size_t len, nixl_capi_opt_args_t opt_args)
@@ -1622,6 +1961,55 @@ nixl_capi_notif_map_get_notif(
   }
 }
 
+nixl_capi_status_t
+nixl_capi_notif_map_has_agent(nixl_capi_notif_map_t map, const char* agent_name, bool* has_agent)
+{
+  if (!map || !agent_name || !has_agent) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    *has_agent = map->notif_map.count(agent_name) != 0;
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (const std::exception& e) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
+nixl_capi_status_t
+nixl_capi_notif_map_erase_agent(nixl_capi_notif_map_t map, const char* agent_name)
+{
+  if (!map || !agent_name) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    map->notif_map.erase(agent_name);
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (const std::exception& e) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
+nixl_capi_status_t
+nixl_capi_notif_map_add_notif(
+    nixl_capi_notif_map_t map, const char* agent_name, const void* data, size_t len)
+{
+  if (!map || !agent_name || (!data && len > 0)) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    map->notif_map[agent_name].emplace_back(static_cast<const char*>(data), len);
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (const std::exception& e) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
 nixl_capi_status_t
 nixl_capi_notif_map_clear(nixl_capi_notif_map_t map)
 {
@@ -83,6 +83,8 @@ typedef enum {
 
 // Core API functions
 nixl_capi_status_t nixl_capi_create_agent(const char* name, nixl_capi_agent_t* agent);
+nixl_capi_status_t nixl_capi_create_agent_with_listener(
+    const char* name, int port, nixl_capi_agent_t* agent);
 
 nixl_capi_status_t nixl_capi_destroy_agent(nixl_capi_agent_t agent);
 
@@ -100,6 +102,8 @@ nixl_capi_status_t nixl_capi_invalidate_local_md(nixl_capi_agent_t agent, nixl_c
 
 // Check if remote metadata is available
 nixl_capi_status_t nixl_capi_check_remote_md(nixl_capi_agent_t agent, const char* remote_name, nixl_capi_xfer_dlist_t descs);
+nixl_capi_status_t nixl_capi_probe_remote_md(
+    nixl_capi_agent_t agent, const char* remote_name, nixl_capi_xfer_dlist_t descs, bool* all_found, size_t* missing_index);
 
 // Send local metadata to etcd
 nixl_capi_status_t nixl_capi_send_local_md(nixl_capi_agent_t agent, nixl_capi_opt_args_t opt_args);
@@ -109,6 +113,8 @@ nixl_capi_status_t nixl_capi_fetch_remote_md(nixl_capi_agent_t agent, const char
 
 // Plugin and parameter functions
 nixl_capi_status_t nixl_capi_get_available_plugins(nixl_capi_agent_t agent, nixl_capi_string_list_t* plugins);
+// Run plugin discovery before any agent is created and list the loaded plugins
+nixl_capi_status_t nixl_capi_preload_plugins(nixl_capi_string_list_t* plugins);
 nixl_capi_status_t nixl_capi_destroy_string_list(nixl_capi_string_list_t list);
 nixl_capi_status_t nixl_capi_string_list_size(nixl_capi_string_list_t list, size_t* size);
 nixl_capi_status_t nixl_capi_string_list_get(nixl_capi_string_list_t list, size_t index, const char** str);
@@ -147,6 +153,8 @@ nixl_capi_status_t nixl_capi_params_create_iterator(nixl_capi_params_t params, n
 nixl_capi_status_t nixl_capi_params_iterator_next(
     nixl_capi_param_iter_t iter, const char** key, const char** value, bool* has_next);
 nixl_capi_status_t nixl_capi_params_destroy_iterator(nixl_capi_param_iter_t iter);
+nixl_capi_status_t nixl_capi_create_params(nixl_capi_params_t* params);
+nixl_capi_status_t nixl_capi_params_set(nixl_capi_params_t params, const char* key, const char* value);
 
 // Memory list access functions
 nixl_capi_status_t nixl_capi_mem_list_is_empty(nixl_capi_mem_list_t list, bool* is_empty);
@@ -167,6 +175,7 @@ nixl_capi_status_t nixl_capi_agent_make_connection(
 // Notification functions
 nixl_capi_status_t nixl_capi_get_notifs(
     nixl_capi_agent_t agent, nixl_capi_notif_map_t notif_map, nixl_capi_opt_args_t opt_args);
+nixl_capi_status_t nixl_capi_count_notifs(nixl_capi_agent_t agent, size_t* count);
 
 nixl_capi_status_t nixl_capi_create_notif_map(nixl_capi_notif_map_t* notif_map);
 
@@ -195,6 +204,9 @@ nixl_capi_status_t nixl_capi_post_xfer_req(
 
 nixl_capi_status_t nixl_capi_get_xfer_status(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl);
 
+nixl_capi_status_t nixl_capi_query_xfer_backend(
+    nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl, nixl_capi_backend_t backend, bool* selected);
+
 nixl_capi_status_t nixl_capi_release_xfer_req(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req);
 
 nixl_capi_status_t nixl_capi_destroy_xfer_req(nixl_capi_xfer_req_t req);
@@ -204,7 +216,12 @@ nixl_capi_status_t nixl_capi_create_xfer_dlist(nixl_capi_mem_type_t mem_type, ni
 nixl_capi_status_t nixl_capi_destroy_xfer_dlist(nixl_capi_xfer_dlist_t dlist);
 nixl_capi_status_t nixl_capi_xfer_dlist_get_type(nixl_capi_xfer_dlist_t dlist, nixl_capi_mem_type_t* mem_type);
 nixl_capi_status_t nixl_capi_xfer_dlist_add_desc(
-    nixl_capi_xfer_dlist_t dlist, uintptr_t addr, size_t len, uint64_t dev_id);
+    nixl_capi_xfer_dlist_t dlist, uintptr_t addr, size_t len, uint64_t dev_id, size_t* index);
+nixl_capi_status_t nixl_capi_xfer_dlist_add_descs(
+    nixl_capi_xfer_dlist_t dlist, const uintptr_t* addrs, const size_t* lens, const uint64_t* dev_ids,
+    size_t count);
+nixl_capi_status_t nixl_capi_xfer_dlist_get_desc(
+    nixl_capi_xfer_dlist_t dlist, size_t index, uintptr_t* addr, size_t* len, uint64_t* dev_id);
 nixl_capi_status_t nixl_capi_xfer_dlist_desc_count(nixl_capi_xfer_dlist_t dlist, size_t* count);
 nixl_capi_status_t nixl_capi_xfer_dlist_len(nixl_capi_xfer_dlist_t dlist, size_t* len);
 nixl_capi_status_t nixl_capi_xfer_dlist_is_empty(nixl_capi_xfer_dlist_t dlist, bool* is_empty);
@@ -216,6 +233,8 @@ nixl_capi_status_t nixl_capi_xfer_dlist_verify_sorted(nixl_capi_xfer_dlist_t dli
 nixl_capi_status_t nixl_capi_xfer_dlist_clear(nixl_capi_xfer_dlist_t dlist);
 nixl_capi_status_t nixl_capi_xfer_dlist_resize(nixl_capi_xfer_dlist_t dlist, size_t new_size);
 nixl_capi_status_t nixl_capi_xfer_dlist_print(nixl_capi_xfer_dlist_t dlist);
+nixl_capi_status_t nixl_capi_xfer_dlist_coalesce(nixl_capi_xfer_dlist_t dlist, size_t* merged);
+nixl_capi_status_t nixl_capi_xfer_dlist_sort(nixl_capi_xfer_dlist_t dlist);
 
 nixl_capi_status_t nixl_capi_create_reg_dlist(nixl_capi_mem_type_t mem_type, nixl_capi_reg_dlist_t* dlist, bool sorted);
 nixl_capi_status_t nixl_capi_destroy_reg_dlist(nixl_capi_reg_dlist_t dlist);
@@ -227,7 +246,15 @@ nixl_capi_reg_dlist_add_desc(nixl_capi_reg_dlist_t dlist,
                              size_t len,
                              uint64_t dev_id,
                              const void *metadata,
-                             size_t metadata_len);
+                             size_t metadata_len,
+                             size_t *index);
+nixl_capi_status_t nixl_capi_reg_dlist_get_desc(nixl_capi_reg_dlist_t dlist,
+                                                size_t index,
+                                                uintptr_t *addr,
+                                                size_t *len,
+                                                uint64_t *dev_id,
+                                                const void **metadata,
+                                                size_t *metadata_len);
 nixl_capi_status_t nixl_capi_reg_dlist_len(nixl_capi_reg_dlist_t dlist, size_t* len);
 nixl_capi_status_t nixl_capi_reg_dlist_desc_count(nixl_capi_reg_dlist_t dlist, size_t* count);
 nixl_capi_status_t nixl_capi_reg_dlist_is_empty(nixl_capi_reg_dlist_t dlist, bool* is_empty);
@@ -235,6 +262,7 @@ nixl_capi_status_t nixl_capi_reg_dlist_is_sorted(nixl_capi_reg_dlist_t dlist, bo
 nixl_capi_status_t nixl_capi_reg_dlist_trim(nixl_capi_reg_dlist_t dlist);
 nixl_capi_status_t nixl_capi_reg_dlist_rem_desc(nixl_capi_reg_dlist_t dlist, int index);
 nixl_capi_status_t nixl_capi_reg_dlist_has_overlaps(nixl_capi_reg_dlist_t dlist, bool* has_overlaps);
+nixl_capi_status_t nixl_capi_reg_dlist_sort(nixl_capi_reg_dlist_t dlist);
 nixl_capi_status_t nixl_capi_reg_dlist_clear(nixl_capi_reg_dlist_t dlist);
 nixl_capi_status_t nixl_capi_reg_dlist_resize(nixl_capi_reg_dlist_t dlist, size_t new_size);
 nixl_capi_status_t nixl_capi_reg_dlist_print(nixl_capi_reg_dlist_t dlist);
@@ -243,6 +271,10 @@ nixl_capi_status_t nixl_capi_notif_map_get_agent_at(nixl_capi_notif_map_t map, s
 nixl_capi_status_t nixl_capi_notif_map_get_notifs_size(nixl_capi_notif_map_t map, const char* agent_name, size_t* size);
 nixl_capi_status_t nixl_capi_notif_map_get_notif(
     nixl_capi_notif_map_t map, const char* agent_name, size_t index, const void** data, size_t* len);
+nixl_capi_status_t nixl_capi_notif_map_has_agent(nixl_capi_notif_map_t map, const char* agent_name, bool* has_agent);
+nixl_capi_status_t nixl_capi_notif_map_erase_agent(nixl_capi_notif_map_t map, const char* agent_name);
+nixl_capi_status_t nixl_capi_notif_map_add_notif(
+    nixl_capi_notif_map_t map, const char* agent_name, const void* data, size_t len);
 nixl_capi_status_t nixl_capi_notif_map_clear(nixl_capi_notif_map_t map);
 
 // Query response list functions